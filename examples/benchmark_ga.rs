@@ -20,7 +20,9 @@ impl Evaluator for MockEvaluator {
         Ok(EvaluationResult {
             energy,
             gradient_norm: Some(0.1),
+            iterations: None,
             relaxed_cluster: Some(cluster.clone()),
+            properties: Default::default(),
         })
     }
 
@@ -53,7 +55,7 @@ fn main() {
     ga.solve(tx);
 
     for msg in rx {
-        if let SolverEvent::Finished = msg {
+        if let SolverEvent::Finished(_) = msg {
             break;
         }
     }
@@ -0,0 +1,97 @@
+//! Runs GA, BH, PT, PSO, MH and the random-search baseline on the analytic benchmark library and prints success rates.
+//!
+//! Usage: `cargo run --release --example benchmark_landscapes [PROBLEM...] [--runs N]`
+use klmc_ultimate::analysis::benchmark::{self, BenchmarkReport};
+use klmc_ultimate::core::domain::{AlgorithmType, Params};
+
+const TOLERANCE: f64 = 1e-3;
+
+fn main() {
+    let mut runs = 10;
+    let mut names = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--runs" {
+            runs = args.next().and_then(|n| n.parse().ok()).unwrap_or(runs);
+        } else {
+            names.push(arg);
+        }
+    }
+
+    let problems = if names.is_empty() {
+        benchmark::catalogue().into_iter().filter(|p| p.atoms <= 13).collect()
+    } else {
+        names.iter().filter_map(|n| benchmark::find(n)).collect::<Vec<_>>()
+    };
+
+    let ga = Params {
+        algorithm: AlgorithmType::GeneticAlgorithm,
+        population_size: 30,
+        elitism_count: 3,
+        max_steps: 50,
+        ..Default::default()
+    };
+    // Reduced units: kT ≈ 0.8 ε
+    let bh = Params {
+        algorithm: AlgorithmType::BasinHopping,
+        temperature: 0.8 / 8.617333262e-5,
+        step_size: 0.4,
+        max_steps: 1000,
+        ..Default::default()
+    };
+
+    // Same evaluation budget as BH, spread over four replicas
+    let pt = Params {
+        algorithm: AlgorithmType::ParallelTempering,
+        replicas: 4,
+        max_temperature: 4.0 / 8.617333262e-5,
+        max_steps: 250,
+        ..bh.clone()
+    };
+
+    let pso = Params {
+        algorithm: AlgorithmType::ParticleSwarm,
+        population_size: 20,
+        max_steps: 50,
+        max_velocity: 0.3,
+        ..Default::default()
+    };
+
+    let mh = Params {
+        algorithm: AlgorithmType::MinimaHopping,
+        max_steps: 1000,
+        md_kinetic_energy: 0.5,
+        md_timestep: 0.01,
+        hop_ediff: 0.5,
+        ..Default::default()
+    };
+
+    // Random-search baseline on the GA's evaluation budget
+    let random = Params {
+        algorithm: AlgorithmType::RandomSearch,
+        ..ga.clone()
+    };
+
+    println!("{:<14} {:<18} {:>8} {:>12} {:>14}", "Problem", "Solver", "Success", "Evals/Hit", "Best (ref)");
+    for problem in &problems {
+        for base in [&ga, &random, &bh, &pt, &pso, &mh] {
+            print_row(&benchmark::run_benchmark(problem, base, runs, TOLERANCE));
+        }
+    }
+}
+
+fn print_row(report: &BenchmarkReport) {
+    let evals = report.mean_evals_to_hit()
+        .map(|e| format!("{:.0}", e))
+        .unwrap_or_else(|| "-".to_string());
+    println!(
+        "{:<14} {:<18} {:>3}/{:<4} {:>12} {:>9.4} ({:.4})",
+        report.problem,
+        format!("{:?}", report.algorithm),
+        report.hits(),
+        report.runs.len(),
+        evals,
+        report.best_energy(),
+        report.global_minimum,
+    );
+}
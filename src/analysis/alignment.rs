@@ -0,0 +1,115 @@
+use crate::core::domain::Cluster;
+use nalgebra::{Point3, Vector3};
+
+/// Solves the square linear assignment problem (Hungarian / Kuhn-Munkres, O(n^3)).
+/// Returns `assignment` with row `i` matched to column `assignment[i]`, minimising the total cost.
+pub fn assignment(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    if n == 0 { return Vec::new(); }
+
+    // 1-based potentials formulation; column 0 is a virtual start node.
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; n + 1];
+    let mut owner = vec![0usize; n + 1]; // owner[col] = row (1-based), 0 = free
+    let mut way = vec![0usize; n + 1];
+
+    for row in 1..=n {
+        owner[0] = row;
+        let mut col0 = 0;
+        let mut min_v = vec![f64::INFINITY; n + 1];
+        let mut used = vec![false; n + 1];
+
+        loop {
+            used[col0] = true;
+            let r = owner[col0];
+            let mut delta = f64::INFINITY;
+            let mut col1 = 0;
+
+            for col in 1..=n {
+                if used[col] { continue; }
+                let reduced = cost[r - 1][col - 1] - u[r] - v[col];
+                if reduced < min_v[col] {
+                    min_v[col] = reduced;
+                    way[col] = col0;
+                }
+                if min_v[col] < delta {
+                    delta = min_v[col];
+                    col1 = col;
+                }
+            }
+
+            for col in 0..=n {
+                if used[col] {
+                    u[owner[col]] += delta;
+                    v[col] -= delta;
+                } else {
+                    min_v[col] -= delta;
+                }
+            }
+
+            col0 = col1;
+            if owner[col0] == 0 { break; }
+        }
+
+        // Augment along the alternating path
+        loop {
+            let prev = way[col0];
+            owner[col0] = owner[prev];
+            col0 = prev;
+            if col0 == 0 { break; }
+        }
+    }
+
+    let mut result = vec![0; n];
+    for col in 1..=n {
+        if owner[col] > 0 {
+            result[owner[col] - 1] = col - 1;
+        }
+    }
+    result
+}
+
+fn centroid(cluster: &Cluster) -> Vector3<f64> {
+    if cluster.atoms.is_empty() { return Vector3::zeros(); }
+    cluster.atoms.iter().map(|a| a.position.coords).sum::<Vector3<f64>>() / cluster.atoms.len() as f64
+}
+
+/// Optimal atom correspondence between two clusters of equal composition.
+///
+/// Both clusters are centred, then atoms of each species are matched by minimal total
+/// squared distance. Returns `map` with `reference.atoms[i]` paired to `target.atoms[map[i]]`,
+/// or `None` if the compositions differ.
+pub fn match_atoms(reference: &Cluster, target: &Cluster) -> Option<Vec<usize>> {
+    if reference.atoms.len() != target.atoms.len() { return None; }
+
+    let c_ref = centroid(reference);
+    let c_tgt = centroid(target);
+    let max_id = reference.atoms.iter().map(|a| a.element_id).max().unwrap_or(0);
+    let mut map = vec![0; reference.atoms.len()];
+
+    for element in 0..=max_id {
+        let rows: Vec<usize> = (0..reference.atoms.len()).filter(|&i| reference.atoms[i].element_id == element).collect();
+        let cols: Vec<usize> = (0..target.atoms.len()).filter(|&j| target.atoms[j].element_id == element).collect();
+        if rows.len() != cols.len() { return None; }
+
+        let cost: Vec<Vec<f64>> = rows.iter()
+            .map(|&i| {
+                let p = reference.atoms[i].position.coords - c_ref;
+                cols.iter().map(|&j| (target.atoms[j].position.coords - c_tgt - p).norm_squared()).collect()
+            })
+            .collect();
+
+        for (k, col) in assignment(&cost).into_iter().enumerate() {
+            map[rows[k]] = cols[col];
+        }
+    }
+    Some(map)
+}
+
+/// Positions of `target` reordered to match `reference` atom-by-atom and translated
+/// onto the reference centroid. `None` if the compositions differ.
+pub fn aligned_positions(reference: &Cluster, target: &Cluster) -> Option<Vec<Point3<f64>>> {
+    let map = match_atoms(reference, target)?;
+    let shift = centroid(reference) - centroid(target);
+    Some(map.iter().map(|&j| target.atoms[j].position + shift).collect())
+}
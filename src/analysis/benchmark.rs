@@ -0,0 +1,214 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use anyhow::Result;
+use crossbeam_channel::unbounded;
+
+use crate::core::chemistry::InteractionGrid;
+use crate::core::domain::{AlgorithmType, Cluster, Params, Species};
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+use crate::engine::native::forcefield::NativeEvaluator;
+use crate::engine::native::potentials::{LennardJones, Morse, PairPotential};
+use crate::solvers::{self, registry::{SolverContext, SolverRegistry}};
+
+// --- Problem Library ---
+
+/// A single-species cluster with a known global minimum, in reduced units.
+#[derive(Debug, Clone)]
+pub struct BenchmarkProblem {
+    pub name: &'static str,
+    pub atoms: usize,
+    /// Published global minimum energy.
+    pub global_minimum: f64,
+    /// Half-width of the random initialisation box.
+    pub box_size: f64,
+    potential: fn() -> Arc<dyn PairPotential>,
+}
+
+impl BenchmarkProblem {
+    pub fn potential(&self) -> Arc<dyn PairPotential> {
+        (self.potential)()
+    }
+
+    pub fn evaluator(&self) -> NativeEvaluator {
+        NativeEvaluator::new(self.potential())
+    }
+
+    /// A single dummy species sized so that random starts avoid core overlap.
+    pub fn species(&self) -> Vec<Species> {
+        vec![Species { symbol: "X".into(), radius_covalent: 0.45, radius_ionic: 0.45, ..Default::default() }]
+    }
+
+    /// `base` with the atom count and box replaced by this problem's.
+    pub fn params(&self, base: &Params) -> Params {
+        Params {
+            atom_count: self.atoms,
+            atom_counts: vec![self.atoms],
+            box_size: self.box_size,
+            ..base.clone()
+        }
+    }
+}
+
+fn lj() -> Arc<dyn PairPotential> { Arc::new(LennardJones::reduced()) }
+fn morse6() -> Arc<dyn PairPotential> { Arc::new(Morse::reduced(6.0)) }
+
+/// The benchmark library, roughly ordered by difficulty.
+///
+/// LJ and Morse (ρ = 6) energies are the Cambridge Cluster Database values.
+pub fn catalogue() -> Vec<BenchmarkProblem> {
+    vec![
+        BenchmarkProblem { name: "LJ7", atoms: 7, global_minimum: -16.505384, box_size: 1.2, potential: lj },
+        BenchmarkProblem { name: "M7 (rho=6)", atoms: 7, global_minimum: -16.207580, box_size: 1.2, potential: morse6 },
+        BenchmarkProblem { name: "LJ13", atoms: 13, global_minimum: -44.326801, box_size: 1.5, potential: lj },
+        BenchmarkProblem { name: "M13 (rho=6)", atoms: 13, global_minimum: -42.439863, box_size: 1.5, potential: morse6 },
+        BenchmarkProblem { name: "LJ38", atoms: 38, global_minimum: -173.928427, box_size: 2.2, potential: lj },
+    ]
+}
+
+/// Looks up a catalogue entry by name (case-insensitive).
+pub fn find(name: &str) -> Option<BenchmarkProblem> {
+    catalogue().into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+// --- Hit Counting ---
+
+/// Evaluator wrapper that counts calls and remembers the call at which
+/// the target energy was first reached.
+pub struct HitCounter {
+    inner: Arc<dyn Evaluator>,
+    target: f64,
+    evaluations: AtomicUsize,
+    first_hit: AtomicUsize, // 0 = not yet
+    best: Mutex<f64>,
+}
+
+impl HitCounter {
+    /// A call "hits" when its energy is at or below `target`.
+    pub fn new(inner: Arc<dyn Evaluator>, target: f64) -> Self {
+        Self {
+            inner,
+            target,
+            evaluations: AtomicUsize::new(0),
+            first_hit: AtomicUsize::new(0),
+            best: Mutex::new(f64::INFINITY),
+        }
+    }
+
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// 1-based index of the first evaluation that reached the target.
+    pub fn first_hit(&self) -> Option<usize> {
+        match self.first_hit.load(Ordering::Relaxed) {
+            0 => None,
+            n => Some(n),
+        }
+    }
+
+    pub fn best_energy(&self) -> f64 {
+        *self.best.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Evaluator for HitCounter {
+    fn name(&self) -> &str { self.inner.name() }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let call = self.evaluations.fetch_add(1, Ordering::Relaxed) + 1;
+        let res = self.inner.evaluate(cluster)?;
+
+        let mut best = self.best.lock().unwrap_or_else(|e| e.into_inner());
+        *best = best.min(res.energy);
+        if res.energy <= self.target {
+            // Parallel callers may finish out of order; keep the earliest call index
+            let _ = self.first_hit.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |cur| {
+                (cur == 0 || call < cur).then_some(call)
+            });
+        }
+        Ok(res)
+    }
+}
+
+// --- Harness ---
+
+/// Result of one seeded solver run.
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    pub seed: u64,
+    pub evaluations: usize,
+    pub evals_to_hit: Option<usize>,
+    pub best_energy: f64,
+}
+
+/// Aggregate over independent runs of one solver on one problem.
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    pub problem: String,
+    pub algorithm: AlgorithmType,
+    pub global_minimum: f64,
+    pub runs: Vec<RunOutcome>,
+}
+
+impl BenchmarkReport {
+    pub fn hits(&self) -> usize {
+        self.runs.iter().filter(|r| r.evals_to_hit.is_some()).count()
+    }
+
+    pub fn success_rate(&self) -> f64 {
+        if self.runs.is_empty() { 0.0 } else { self.hits() as f64 / self.runs.len() as f64 }
+    }
+
+    /// Mean evaluations-to-hit over the successful runs.
+    pub fn mean_evals_to_hit(&self) -> Option<f64> {
+        let hits: Vec<usize> = self.runs.iter().filter_map(|r| r.evals_to_hit).collect();
+        if hits.is_empty() {
+            None
+        } else {
+            Some(hits.iter().sum::<usize>() as f64 / hits.len() as f64)
+        }
+    }
+
+    pub fn best_energy(&self) -> f64 {
+        self.runs.iter().map(|r| r.best_energy).fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Runs `algorithm` `runs` times on `problem`, seeding run `i` with `base.seed + i`
+/// (or `i + 1` if `base.seed` is 0). A run succeeds once any evaluation lands within
+/// `tolerance` of the global minimum. Solver events are discarded.
+pub fn run_benchmark(problem: &BenchmarkProblem, base: &Params, runs: usize, tolerance: f64) -> BenchmarkReport {
+    let species = problem.species();
+    let grid = Arc::new(InteractionGrid::new(&species, 0.75));
+    let native: Arc<dyn Evaluator> = Arc::new(problem.evaluator());
+    let registry = SolverRegistry::builtin();
+
+    let outcomes = (0..runs as u64)
+        .map(|i| {
+            let seed = if base.seed == 0 { i + 1 } else { base.seed + i };
+            let params = Params { seed, ..problem.params(base) };
+            let counter = Arc::new(HitCounter::new(native.clone(), problem.global_minimum + tolerance));
+
+            let (tx, _rx) = unbounded();
+            let mut ctx = SolverContext::new(counter.clone(), grid.clone(), params);
+            ctx.potential = Some(problem.potential());
+            if let Ok(mut solver) = registry.build(ctx) {
+                solvers::run(solver.as_mut(), tx);
+            }
+
+            RunOutcome {
+                seed,
+                evaluations: counter.evaluations(),
+                evals_to_hit: counter.first_hit(),
+                best_energy: counter.best_energy(),
+            }
+        })
+        .collect();
+
+    BenchmarkReport {
+        problem: problem.name.to_string(),
+        algorithm: base.algorithm,
+        global_minimum: problem.global_minimum,
+        runs: outcomes,
+    }
+}
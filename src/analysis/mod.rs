@@ -1 +1,5 @@
+pub mod alignment;
+pub mod benchmark;
+pub mod pareto;
+pub mod symmetry;
 pub mod topology;
\ No newline at end of file
@@ -0,0 +1,89 @@
+/// True if `a` is no worse than `b` in every objective and strictly better in at least one.
+/// All objectives are minimised; callers negate the ones they want to maximise.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    let mut strictly = false;
+    for (x, y) in a.iter().zip(b) {
+        if x > y {
+            return false;
+        }
+        if x < y {
+            strictly = true;
+        }
+    }
+    strictly
+}
+
+/// Fast non-dominated sort: indices of `points` grouped into fronts, best front first.
+pub fn non_dominated_fronts(points: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let n = points.len();
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut domination_count = vec![0usize; n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if dominates(&points[i], &points[j]) {
+                dominated_by[i].push(j);
+                domination_count[j] += 1;
+            } else if dominates(&points[j], &points[i]) {
+                dominated_by[j].push(i);
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut current: Vec<usize> = (0..n).filter(|&i| domination_count[i] == 0).collect();
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        for &i in &current {
+            for &j in &dominated_by[i] {
+                domination_count[j] -= 1;
+                if domination_count[j] == 0 {
+                    next.push(j);
+                }
+            }
+        }
+        fronts.push(current);
+        current = next;
+    }
+    fronts
+}
+
+/// Crowding distance of each member of `front` (same order). Boundary points of every
+/// objective get `f64::INFINITY`.
+pub fn crowding_distance(points: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let mut distance = vec![0.0; front.len()];
+    if front.len() <= 2 {
+        return vec![f64::INFINITY; front.len()];
+    }
+
+    let objectives = points[front[0]].len();
+    let mut order: Vec<usize> = (0..front.len()).collect();
+    let columns = (0..objectives).map(|m| front.iter().map(|&i| points[i][m]).collect::<Vec<f64>>());
+    for column in columns {
+        order.sort_by(|&a, &b| column[a].total_cmp(&column[b]));
+        let (first, last) = (order[0], order[order.len() - 1]);
+        let span = column[last] - column[first];
+        distance[first] = f64::INFINITY;
+        distance[last] = f64::INFINITY;
+        if span <= 0.0 {
+            continue;
+        }
+        for w in order.windows(3) {
+            distance[w[1]] += (column[w[2]] - column[w[0]]) / span;
+        }
+    }
+    distance
+}
+
+/// `(front, crowding distance)` for every point. Sorting by front ascending, then crowding
+/// descending, gives the NSGA-II ranking (Deb et al., IEEE Trans. Evol. Comput. 6, 182).
+pub fn nsga_keys(points: &[Vec<f64>]) -> Vec<(usize, f64)> {
+    let mut keys = vec![(0, 0.0); points.len()];
+    for (rank, front) in non_dominated_fronts(points).iter().enumerate() {
+        for (&i, d) in front.iter().zip(crowding_distance(points, front)) {
+            keys[i] = (rank, d);
+        }
+    }
+    keys
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use nalgebra::{Matrix3, Point3, Vector3};
+
+use crate::core::domain::{Cluster, Lattice};
+
+/// Cartesian tolerance (Å) for matching a transformed atom to a host atom.
+const POSITION_TOLERANCE: f64 = 0.05;
+/// Relative tolerance on the metric tensor when testing a point operation.
+const METRIC_TOLERANCE: f64 = 1e-4;
+
+/// Signed permutation matrices (the 48 operations of the cube), in fractional coordinates.
+fn signed_permutations() -> Vec<Matrix3<f64>> {
+    const AXES: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+    let mut ops = Vec::with_capacity(48);
+    for perm in AXES {
+        for signs in 0..8 {
+            let mut m = Matrix3::zeros();
+            for (row, &col) in perm.iter().enumerate() {
+                m[(row, col)] = if signs & (1 << row) == 0 { 1.0 } else { -1.0 };
+            }
+            ops.push(m);
+        }
+    }
+    ops
+}
+
+/// Fractional displacement wrapped to [-0.5, 0.5) and measured in Cartesian Å.
+fn periodic_gap(lattice: &Lattice, a: &Point3<f64>, b: &Point3<f64>) -> f64 {
+    let d: Vector3<f64> = (b - a).map(|x| x - x.round());
+    (lattice.vectors * d).norm()
+}
+
+/// Symmetry operations of a periodic host, expressed as permutations of `sites`.
+///
+/// Candidate operations are the signed axis permutations that preserve the lattice metric,
+/// combined with every translation that maps a reference atom onto an atom of the same
+/// species. An operation is kept if it maps the whole host onto itself and `sites` onto
+/// itself. Entry `p[i] = j` means site `sites[i]` is carried onto site `sites[j]`. The
+/// identity is always included. A host without a lattice yields only the identity.
+pub fn site_permutations(host: &Cluster, sites: &[usize]) -> Vec<Vec<usize>> {
+    let identity: Vec<usize> = (0..sites.len()).collect();
+    let Some(lattice) = &host.lattice else { return vec![identity]; };
+    if host.atoms.is_empty() { return vec![identity]; }
+
+    let frac: Vec<Point3<f64>> = host.atoms.iter().map(|a| lattice.to_fractional(&a.position)).collect();
+    let metric = lattice.vectors.transpose() * lattice.vectors;
+    let scale = metric.norm();
+
+    let mut site_index = vec![None; host.atoms.len()];
+    for (i, &s) in sites.iter().enumerate() {
+        if let Some(slot) = site_index.get_mut(s) { *slot = Some(i); }
+    }
+
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    seen.insert(identity.clone());
+    let mut perms = vec![identity];
+
+    for rot in signed_permutations() {
+        if (rot.transpose() * metric * rot - metric).norm() > METRIC_TOLERANCE * scale {
+            continue;
+        }
+        let rotated: Vec<Point3<f64>> = frac.iter().map(|f| Point3::from(rot * f.coords)).collect();
+
+        for (j, target) in frac.iter().enumerate() {
+            if host.atoms[j].element_id != host.atoms[0].element_id { continue; }
+            let shift = target - rotated[0];
+
+            // Image of every host atom under (rot, shift)
+            let image: Option<Vec<usize>> = rotated.iter().enumerate().map(|(i, r)| {
+                let moved = r + shift;
+                (0..host.atoms.len()).find(|&k| {
+                    host.atoms[k].element_id == host.atoms[i].element_id
+                        && periodic_gap(lattice, &moved, &frac[k]) < POSITION_TOLERANCE
+                })
+            }).collect();
+            let Some(image) = image else { continue; };
+
+            let perm: Option<Vec<usize>> = sites.iter().map(|&s| image.get(s).and_then(|&k| site_index[k])).collect();
+            if let Some(perm) = perm {
+                if seen.insert(perm.clone()) {
+                    perms.push(perm);
+                }
+            }
+        }
+    }
+    perms
+}
+
+/// Canonical representative of a site occupancy under a set of site permutations:
+/// the lexicographically smallest relabelled occupancy. Symmetry-equivalent orderings
+/// share the same key.
+pub fn canonical_occupancy(occupancy: &[usize], perms: &[Vec<usize>]) -> Vec<usize> {
+    let mut best = occupancy.to_vec();
+    let mut image = vec![0; occupancy.len()];
+    for p in perms {
+        for (i, &species) in occupancy.iter().enumerate() {
+            image[p[i]] = species;
+        }
+        if image < best {
+            best.clone_from(&image);
+        }
+    }
+    best
+}
+
+/// Approximate point symmetry of a finite cluster, from 0 (none) to 1.
+///
+/// The cluster is centred and rotated into its principal axes of inertia (unit masses). For each
+/// of the seven non-identity operations of D2h in that frame (inversion, three C2 axes, three
+/// mirror planes), count the atoms whose image lies within `tolerance` Å of an atom of the same
+/// species. The score is the mean fraction over the seven operations. Degenerate principal axes
+/// (spherical or symmetric tops) leave the frame underdetermined, so such clusters can score below 1.
+pub fn point_symmetry_score(cluster: &Cluster, tolerance: f64) -> f64 {
+    const OPS: [[f64; 3]; 7] = [
+        [-1.0, -1.0, -1.0],
+        [1.0, -1.0, -1.0],
+        [-1.0, 1.0, -1.0],
+        [-1.0, -1.0, 1.0],
+        [-1.0, 1.0, 1.0],
+        [1.0, -1.0, 1.0],
+        [1.0, 1.0, -1.0],
+    ];
+    let n = cluster.atoms.len();
+    if n < 2 { return 1.0; }
+
+    let centroid = cluster.atoms.iter().map(|a| a.position.coords).sum::<Vector3<f64>>() / n as f64;
+    let rel: Vec<Vector3<f64>> = cluster.atoms.iter().map(|a| a.position.coords - centroid).collect();
+    let mut inertia = Matrix3::zeros();
+    for r in &rel {
+        inertia += Matrix3::identity() * r.norm_squared() - r * r.transpose();
+    }
+    let axes = inertia.symmetric_eigen().eigenvectors;
+    let local: Vec<Vector3<f64>> = rel.iter().map(|r| axes.transpose() * r).collect();
+
+    let tol_sq = tolerance * tolerance;
+    let matched: usize = OPS.iter().map(|op| {
+        let op = Vector3::from(*op);
+        (0..n).filter(|&i| {
+            let image = local[i].component_mul(&op);
+            (0..n).any(|j| {
+                cluster.atoms[j].element_id == cluster.atoms[i].element_id
+                    && (local[j] - image).norm_squared() < tol_sq
+            })
+        }).count()
+    }).sum();
+    matched as f64 / (OPS.len() * n) as f64
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use nalgebra::{Point3, Vector3, Matrix3};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -72,12 +73,43 @@ impl Lattice {
 
 // --- The Core Entity ---
 
+/// Why a candidate was rejected. Attached to `ClusterStatus::Discarded`
+/// and tallied by solvers so the UI can show what is killing candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, thiserror::Error)]
+pub enum EvalFailure {
+    /// Rejected before evaluation: operators produced overlapping atoms.
+    #[error("Atomic overlap")]
+    Overlap,
+    #[error("Convergence failure")]
+    Convergence,
+    #[error("Geometric collapse")]
+    GeometricCollapse,
+    #[error("Atom count mismatch")]
+    AtomCountMismatch,
+    /// The engine process could not be started.
+    #[error("Failed to launch engine")]
+    Spawn,
+    #[error("Unreadable engine output")]
+    Parse,
+    /// The engine ran but reported an internal error.
+    #[error("Engine error")]
+    Engine,
+    /// Filtered out by a cheap pre-screening stage.
+    #[error("Screened out")]
+    Screened,
+    /// Remote workers unreachable.
+    #[error("Transport failure")]
+    Transport,
+    #[error("Unclassified failure")]
+    Other,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ClusterStatus {
     Born,        // Just created
     Valid,       // Checks passed
     Evaluated,   // Physics engine returned energy
-    Discarded,   // Failed
+    Discarded(EvalFailure), // Failed
     Elite,       // Hall of Fame
 }
 
@@ -95,6 +127,9 @@ pub struct Cluster {
     pub gradient_norm: Option<f64>,
     pub pmoi: Option<Vector3<f64>>,
     pub hash_key: Option<String>,
+    /// Named scalar properties reported by the evaluator (e.g. `dipole`, `gap`).
+    #[serde(default)]
+    pub properties: BTreeMap<String, f64>,
     
     pub status: ClusterStatus,
 }
@@ -111,6 +146,7 @@ impl Cluster {
             gradient_norm: None,
             pmoi: None,
             hash_key: None,
+            properties: BTreeMap::new(),
             status: ClusterStatus::Born,
         }
     }
@@ -213,10 +249,140 @@ impl Cluster {
 pub enum AlgorithmType {
     GeneticAlgorithm,
     BasinHopping,
+    SimulatedAnnealing,
+    ParticleSwarm,
+    ParallelTempering,
+    MinimaHopping,
+    RandomSearch,
     ScanBox,
     SolidSolution,
 }
 
+/// Scheduling strategy for the Genetic Algorithm.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum GaMode {
+    /// Breed a full batch, wait for every evaluation, then rank.
+    #[default]
+    Generational,
+    /// Keep `threads` evaluations in flight and insert each child as soon as it finishes.
+    SteadyState,
+    /// `islands` generational subpopulations evolving in parallel, exchanging elites every
+    /// `migration_interval` generations.
+    Island,
+}
+
+/// Which islands send emigrants to which in the island-model GA.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum MigrationTopology {
+    /// Island `i` sends to island `i + 1` (wrapping around).
+    #[default]
+    Ring,
+    /// Every island sends to every other island.
+    FullyConnected,
+}
+
+/// One objective of a multi-objective GA run: `energy` or a named entry of
+/// `Cluster::properties`, minimised unless `maximize` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Objective {
+    pub property: String,
+    pub maximize: bool,
+}
+
+impl Objective {
+    pub const ENERGY: &'static str = "energy";
+
+    /// Parses `name` (minimise) or `max:name` (maximise).
+    pub fn parse(spec: &str) -> Self {
+        let spec = spec.trim();
+        match spec.strip_prefix("max:") {
+            Some(name) => Self { property: name.trim().to_lowercase(), maximize: true },
+            None => Self { property: spec.to_lowercase(), maximize: false },
+        }
+    }
+
+    /// The raw value, or `None` if the cluster has not been evaluated or lacks the property.
+    pub fn value(&self, cluster: &Cluster) -> Option<f64> {
+        if self.property == Self::ENERGY {
+            cluster.energy
+        } else {
+            cluster.properties.get(&self.property).copied()
+        }
+    }
+
+    /// The value oriented for minimisation.
+    pub fn cost(&self, cluster: &Cluster) -> Option<f64> {
+        self.value(cluster).map(|v| if self.maximize { -v } else { v })
+    }
+
+    /// Short label with the direction, e.g. `symmetry ↑`.
+    pub fn label(&self) -> String {
+        format!("{} {}", self.property, if self.maximize { "↑" } else { "↓" })
+    }
+}
+
+/// Perturbation classes for basin-hopping moves.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BhMove {
+    /// Random displacement of every atom by up to `step_size`.
+    Rattle,
+    /// Random displacement of the low-coordinated (surface) atoms only.
+    Surface,
+    /// Exchange of two atoms of different species.
+    Swap,
+}
+
+impl BhMove {
+    pub const ALL: [BhMove; 3] = [BhMove::Rattle, BhMove::Surface, BhMove::Swap];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BhMove::Rattle => "rattle",
+            BhMove::Surface => "surface",
+            BhMove::Swap => "swap",
+        }
+    }
+
+    /// Parses `name` or `name:weight` (weight defaults to 1). Unknown names give `None`.
+    pub fn parse(spec: &str) -> Option<(Self, f64)> {
+        let (name, weight) = match spec.split_once(':') {
+            Some((name, w)) => (name, w.trim().parse().ok()?),
+            None => (spec, 1.0),
+        };
+        let name = name.trim().to_lowercase();
+        let mv = Self::ALL.into_iter().find(|m| m.name() == name)?;
+        Some((mv, weight))
+    }
+}
+
+/// Acceptance criterion for basin-hopping moves (see `solvers::acceptance`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum Acceptance {
+    /// Uphill with probability `exp(-ΔE / kT)`.
+    #[default]
+    Metropolis,
+    /// Uphill whenever `ΔE < kT`.
+    Threshold,
+    /// Downhill moves only.
+    Downhill,
+    /// Generalised Boltzmann factor with exponent `tsallis_q`.
+    Tsallis,
+    /// Metropolis, rejecting moves into the last `novelty_memory` visited basins.
+    Novelty,
+}
+
+/// Temperature schedule for Simulated Annealing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum CoolingSchedule {
+    /// Constant decrement from `temperature` to `final_temperature`.
+    Linear,
+    /// Constant ratio from `temperature` to `final_temperature`.
+    #[default]
+    Geometric,
+    /// Steers the temperature so the acceptance ratio tracks a falling target.
+    Adaptive,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Params {
     pub algorithm: AlgorithmType,
@@ -234,11 +400,99 @@ pub struct Params {
     pub mutation_rate: f64,
     pub crossover_rate: f64,
     pub elitism_count: usize,
+    pub ga_mode: GaMode,
+    /// Learn mutation operator probabilities from the children they produce
+    /// (false = always the classic rotate/rattle/swap recipe).
+    pub adaptive_operators: bool,
+    /// Rank by NSGA-II non-dominated sorting over these objectives (empty = energy only).
+    pub objectives: Vec<Objective>,
+    /// Subpopulations of `population_size` each (island mode only).
+    pub islands: usize,
+    /// Generations between migrations.
+    pub migration_interval: usize,
+    /// Elites each island sends along every outgoing link.
+    pub migration_size: usize,
+    pub migration_topology: MigrationTopology,
+    /// Ratio of the last island's mutation rate to the first's; rates in between are spaced
+    /// geometrically (1 = every island uses `mutation_rate`).
+    pub mutation_spread: f64,
+    /// Generations between memetic phases, in which the best unique members take a short
+    /// BH chain at `temperature` and `step_size` (0 = off; energy-only runs).
+    pub memetic_interval: usize,
+    /// Members refined per memetic phase (per island).
+    pub memetic_elites: usize,
+    /// BH moves in each member's chain.
+    pub memetic_steps: usize,
     
     // BH Specific
     pub temperature: f64,
     pub step_size: f64,
     pub max_steps: usize,
+    /// Independent BH walkers run in parallel (1 = classic single walker).
+    pub walkers: usize,
+    /// Steps without improvement after which a walker stuck in a known funnel restarts (0 = never).
+    pub restart_after: usize,
+    /// Move classes with their relative selection weights.
+    pub bh_moves: Vec<(BhMove, f64)>,
+    pub acceptance: Acceptance,
+    /// Tsallis acceptance: `q` (1 = Metropolis).
+    pub tsallis_q: f64,
+    /// Novelty acceptance: recently visited basins that may not be re-entered.
+    pub novelty_memory: usize,
+    /// Rescale `step_size` every `adapt_interval` moves to hold `target_acceptance`.
+    pub adaptive_step: bool,
+    pub target_acceptance: f64,
+    pub adapt_interval: usize,
+    /// Also adapt the temperature (raised when too few moves are accepted).
+    pub adapt_temperature: bool,
+
+    // SA Specific (`temperature` is the starting temperature)
+    pub cooling: CoolingSchedule,
+    pub final_temperature: f64,
+    /// Steps without a new best before reheating (0 = never reheat).
+    pub reheat_after: usize,
+    /// Reheat temperature as a fraction of the starting temperature.
+    pub reheat_fraction: f64,
+
+    // PSO Specific (`population_size` particles, `max_steps` iterations)
+    pub inertia: f64,
+    pub cognitive_weight: f64,
+    pub social_weight: f64,
+    /// Per-atom speed limit (Å per iteration).
+    pub max_velocity: f64,
+
+    // Parallel Tempering Specific (ladder runs geometrically from `temperature`)
+    pub replicas: usize,
+    pub max_temperature: f64,
+    /// BH steps each replica takes between swap attempts.
+    pub swap_interval: usize,
+
+    // Minima Hopping Specific (`max_steps` hops)
+    /// Initial kinetic energy per atom for the MD escape (eV).
+    pub md_kinetic_energy: f64,
+    pub md_timestep: f64,
+    /// Potential-energy minima crossed along the MD trajectory before it stops.
+    pub md_minima: usize,
+    /// Initial acceptance threshold: a new minimum is accepted if it is less than this much higher (eV).
+    pub hop_ediff: f64,
+
+    // ScanBox Specific (`population_size` structures per box size)
+    pub scan_box_min: f64,
+    pub scan_box_max: f64,
+    /// Evenly spaced box sizes from `scan_box_min` to `scan_box_max` inclusive.
+    pub scan_steps: usize,
+
+    // Stopping Criteria (checked after every step, on top of `max_steps`)
+    /// Stop once the best energy is at or below this (eV).
+    pub target_energy: Option<f64>,
+    /// Wall-clock budget in seconds (0 = unlimited).
+    pub time_limit: f64,
+    /// Energy evaluations budget (0 = unlimited).
+    pub max_evaluations: usize,
+    /// Steps (generations for the GA) without a new best before stopping (0 = never).
+    pub stall_steps: usize,
+    /// Stop once the best structure has been found this many times (0 = never).
+    pub repeat_hits: usize,
 }
 
 impl Default for Params {
@@ -255,9 +509,53 @@ impl Default for Params {
             mutation_rate: 0.1,
             crossover_rate: 0.6,
             elitism_count: 2,
+            ga_mode: GaMode::Generational,
+            adaptive_operators: false,
+            objectives: Vec::new(),
+            islands: 4,
+            migration_interval: 10,
+            migration_size: 2,
+            migration_topology: MigrationTopology::Ring,
+            mutation_spread: 1.0,
+            memetic_interval: 0,
+            memetic_elites: 3,
+            memetic_steps: 10,
             temperature: 300.0,
             step_size: 0.1,
             max_steps: 100,
+            walkers: 1,
+            restart_after: 0,
+            bh_moves: vec![(BhMove::Rattle, 1.0)],
+            acceptance: Acceptance::Metropolis,
+            tsallis_q: 1.5,
+            novelty_memory: 20,
+            adaptive_step: false,
+            target_acceptance: 0.5,
+            adapt_interval: 50,
+            adapt_temperature: false,
+            cooling: CoolingSchedule::Geometric,
+            final_temperature: 1.0,
+            reheat_after: 0,
+            reheat_fraction: 0.5,
+            inertia: 0.7,
+            cognitive_weight: 1.5,
+            social_weight: 1.5,
+            max_velocity: 0.5,
+            replicas: 4,
+            max_temperature: 3000.0,
+            swap_interval: 10,
+            md_kinetic_energy: 0.2,
+            md_timestep: 0.01,
+            md_minima: 3,
+            hop_ediff: 0.1,
+            scan_box_min: 4.0,
+            scan_box_max: 10.0,
+            scan_steps: 7,
+            target_energy: None,
+            time_limit: 0.0,
+            max_evaluations: 0,
+            stall_steps: 0,
+            repeat_hits: 0,
         }
     }
 }
@@ -28,6 +28,23 @@ pub fn distance_sq(p1: &Point3<f64>, p2: &Point3<f64>, lattice: Option<&Lattice>
     }
 }
 
+/// Returns the separation vector `p2 - p1`.
+/// If `lattice` is provided, the shortest periodic image is used (MIC).
+#[inline]
+pub fn displacement(p1: &Point3<f64>, p2: &Point3<f64>, lattice: Option<&Lattice>) -> Vector3<f64> {
+    let d_cart = p2 - p1;
+    match lattice {
+        Some(lat) => {
+            let mut d_frac = lat.inverse * d_cart;
+            d_frac.x -= d_frac.x.round();
+            d_frac.y -= d_frac.y.round();
+            d_frac.z -= d_frac.z.round();
+            lat.vectors * d_frac
+        }
+        None => d_cart,
+    }
+}
+
 /// Checks a cluster for any physical overlaps (hard collisions).
 /// Returns `true` if the cluster is valid (no overlaps).
 pub fn check_overlap(cluster: &Cluster, grid: &InteractionGrid) -> bool {
@@ -54,6 +71,33 @@ pub fn check_overlap(cluster: &Cluster, grid: &InteractionGrid) -> bool {
     true
 }
 
+/// Neighbour count of every atom: pairs closer than `bond_factor` times the mean
+/// nearest-neighbour distance are bonded (MIC for periodic cells).
+pub fn coordination_numbers(cluster: &Cluster, bond_factor: f64) -> Vec<usize> {
+    let atoms = &cluster.atoms;
+    let n = atoms.len();
+    if n < 2 { return vec![0; n]; }
+    let lattice = cluster.lattice.as_ref();
+
+    let mut d_sq = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let d = distance_sq(&atoms[i].position, &atoms[j].position, lattice);
+            d_sq[i][j] = d;
+            d_sq[j][i] = d;
+        }
+    }
+
+    let mean_nn = (0..n)
+        .map(|i| (0..n).filter(|&j| j != i).map(|j| d_sq[i][j]).fold(f64::MAX, f64::min).sqrt())
+        .sum::<f64>() / n as f64;
+    let cutoff_sq = (bond_factor * mean_nn).powi(2);
+
+    (0..n)
+        .map(|i| (0..n).filter(|&j| j != i && d_sq[i][j] < cutoff_sq).count())
+        .collect()
+}
+
 /// Moves a point into the primary unit cell (Periodic only) or centers it (0D).
 /// 
 /// For 3D (Periodic): Wraps atoms into [0, 1) fractional box.
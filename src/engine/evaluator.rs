@@ -1,16 +1,26 @@
-use crate::core::domain::Cluster;
+use std::collections::BTreeMap;
+use crate::core::domain::{Cluster, EvalFailure};
+use crate::engine::instrument::EvaluatorStats;
+use crate::engine::screening::ScreenedOut;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 /// The result of a physical evaluation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvaluationResult {
     /// The potential energy (eV).
     pub energy: f64,
     /// The gradient norm (eV/Å) - useful for checking convergence quality.
     pub gradient_norm: Option<f64>,
+    /// Optimiser steps taken, if the engine reports them.
+    #[serde(default)]
+    pub iterations: Option<usize>,
     /// The updated cluster with relaxed coordinates.
     /// Returns None if the geometry exploded or failed.
     pub relaxed_cluster: Option<Cluster>,
+    /// Extra named properties (e.g. `gap`), copied onto `Cluster::properties`.
+    #[serde(default)]
+    pub properties: BTreeMap<String, f64>,
 }
 
 /// A generic interface for physics engines.
@@ -21,4 +31,25 @@ pub trait Evaluator: Send + Sync {
     
     /// Returns the name of the engine (e.g., "GULP 6.1").
     fn name(&self) -> &str;
+
+    /// Aggregated call statistics, if this evaluator collects them
+    /// (see `InstrumentedEvaluator`).
+    fn stats(&self) -> Option<EvaluatorStats> { None }
+}
+
+/// Builds an error of the given class with a human-readable detail message.
+/// `classify_failure` recovers the class; `{:#}` formatting shows both.
+pub fn failure(kind: EvalFailure, detail: impl std::fmt::Display + Send + Sync + 'static) -> anyhow::Error {
+    anyhow::Error::new(kind).context(detail)
+}
+
+/// Recovers the failure class from an evaluator error.
+/// Errors that carry no `EvalFailure` are reported as `Other`.
+pub fn classify_failure(err: &anyhow::Error) -> EvalFailure {
+    if err.downcast_ref::<ScreenedOut>().is_some() {
+        return EvalFailure::Screened;
+    }
+    err.chain()
+        .find_map(|e| e.downcast_ref::<EvalFailure>().copied())
+        .unwrap_or(EvalFailure::Other)
 }
\ No newline at end of file
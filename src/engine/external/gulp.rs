@@ -1,9 +1,9 @@
 use std::process::{Command, Stdio};
 use std::io::Write;
-use anyhow::{anyhow, Context, Result, bail};
+use anyhow::{anyhow, Result};
 
-use crate::core::domain::{Cluster, Species};
-use crate::engine::evaluator::{Evaluator, EvaluationResult};
+use crate::core::domain::{Cluster, EvalFailure, Species};
+use crate::engine::evaluator::{Evaluator, EvaluationResult, failure};
 
 /// A high-performance, in-memory wrapper for GULP.
 /// Streams input/output via pipes to avoid disk latency where possible.
@@ -85,18 +85,19 @@ impl GulpEvaluator {
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .context("Failed to spawn GULP executable")?;
+            .map_err(|e| failure(EvalFailure::Spawn, format!("Failed to spawn GULP executable: {}", e)))?;
 
         if let Some(mut stdin) = child.stdin.take() {
             stdin.write_all(input_data.as_bytes())
-                .context("Failed to write to GULP stdin")?;
+                .map_err(|e| failure(EvalFailure::Engine, format!("Failed to write to GULP stdin: {}", e)))?;
         }
 
-        let output = child.wait_with_output().context("Failed to read GULP output")?;
+        let output = child.wait_with_output()
+            .map_err(|e| failure(EvalFailure::Engine, format!("Failed to read GULP output: {}", e)))?;
 
         if !output.status.success() {
             let err_msg = String::from_utf8_lossy(&output.stderr);
-            bail!("GULP exited with error: {}", err_msg);
+            return Err(failure(EvalFailure::Engine, format!("GULP exited with error: {}", err_msg)));
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
@@ -111,13 +112,13 @@ impl GulpEvaluator {
                     let tokens: Vec<&str> = parts.split_whitespace().collect();
                     if let Some(val_str) = tokens.first() {
                         let val = val_str.parse::<f64>()
-                            .context("Failed to parse energy float")?;
+                            .map_err(|_| failure(EvalFailure::Parse, format!("Failed to parse energy float '{}'", val_str)))?;
                         return Ok(val);
                     }
                 }
             }
         }
-        bail!("Could not find final energy in GULP output");
+        Err(failure(EvalFailure::Parse, "Could not find final energy in GULP output"))
     }
 
     fn parse_gnorm(&self, output: &str) -> Option<f64> {
@@ -133,6 +134,14 @@ impl GulpEvaluator {
         None
     }
 
+    /// Number of optimisation cycles, from the last "Cycle:" progress line.
+    fn parse_cycles(&self, output: &str) -> Option<usize> {
+        output.lines().rev()
+            .find_map(|line| line.trim_start().strip_prefix("Cycle:"))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|n| n.parse().ok())
+    }
+
     fn parse_geometry(&self, output: &str, original: &Cluster) -> Result<Cluster> {
         let mut new_cluster = original.clone();
         let lines: Vec<&str> = output.lines().collect();
@@ -153,7 +162,7 @@ impl GulpEvaluator {
             }
         }
 
-        let start = start_idx.ok_or_else(|| anyhow!("No final coordinates found in GULP output"))?;
+        let start = start_idx.ok_or_else(|| failure(EvalFailure::Parse, "No final coordinates found in GULP output"))?;
         let expected_atoms = original.atoms.len();
         let mut count = 0;
 
@@ -173,7 +182,7 @@ impl GulpEvaluator {
             let z: f64 = parts[5].parse().unwrap_or(f64::NAN);
 
             if x.is_nan() || y.is_nan() || z.is_nan() {
-                bail!("Parsed NaN coordinates from GULP output");
+                return Err(failure(EvalFailure::Parse, "Parsed NaN coordinates from GULP output"));
             }
 
             if is_fractional {
@@ -181,7 +190,7 @@ impl GulpEvaluator {
                     let frac = nalgebra::Point3::new(x, y, z);
                     new_cluster.atoms[count].position = lat.to_cartesian(&frac);
                 } else {
-                    bail!("GULP returned fractional coords but cluster has no lattice");
+                    return Err(failure(EvalFailure::Parse, "GULP returned fractional coords but cluster has no lattice"));
                 }
             } else {
                 new_cluster.atoms[count].position = nalgebra::Point3::new(x, y, z);
@@ -192,7 +201,10 @@ impl GulpEvaluator {
 
         // STRICT VALIDATION
         if count != expected_atoms {
-            bail!("GULP atom count mismatch: expected {}, got {}. Geometry update aborted.", expected_atoms, count);
+            return Err(failure(
+                EvalFailure::AtomCountMismatch,
+                format!("GULP atom count mismatch: expected {}, got {}. Geometry update aborted.", expected_atoms, count),
+            ));
         }
 
         Ok(new_cluster)
@@ -200,13 +212,13 @@ impl GulpEvaluator {
 
     fn check_errors(&self, output: &str) -> Result<()> {
         if output.contains("Conditions for a minimum have not been satisfied") {
-            bail!("Convergence failure");
+            return Err(EvalFailure::Convergence.into());
         }
         if output.contains("Interatomic distance too small") {
-            bail!("Geometric collapse");
+            return Err(EvalFailure::GeometricCollapse.into());
         }
         if output.contains("Dump of error info") {
-            bail!("Internal GULP error");
+            return Err(failure(EvalFailure::Engine, "Internal GULP error"));
         }
         Ok(())
     }
@@ -228,13 +240,15 @@ impl Evaluator for GulpEvaluator {
         // so the solver knows this evaluation is invalid/partial.
         let relaxed_cluster = match self.parse_geometry(&output_str, cluster) {
             Ok(c) => Some(c),
-            Err(e) => return Err(anyhow!("Geometry parsing failed: {}", e)),
+            Err(e) => return Err(e.context("Geometry parsing failed")),
         };
 
         Ok(EvaluationResult {
             energy,
             gradient_norm: gnorm,
+            iterations: self.parse_cycles(&output_str),
             relaxed_cluster,
+            properties: Default::default(),
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use anyhow::Result;
+
+use crate::core::domain::Cluster;
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+
+/// Wall-time bucket upper bounds (seconds): 1ms .. 10min.
+const TIME_BOUNDS: [f64; 7] = [0.001, 0.01, 0.1, 1.0, 10.0, 60.0, 600.0];
+/// Optimiser iteration bucket upper bounds.
+const ITER_BOUNDS: [f64; 6] = [10.0, 50.0, 100.0, 500.0, 1000.0, 5000.0];
+
+/// A fixed-bucket histogram. Bucket `i` counts values below `bounds[i]`
+/// (and at or above `bounds[i - 1]`); the final bucket catches everything larger.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub bounds: Vec<f64>,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn new(bounds: &[f64]) -> Self {
+        Self {
+            bounds: bounds.to_vec(),
+            counts: vec![0; bounds.len() + 1],
+        }
+    }
+
+    pub fn record(&mut self, value: f64) {
+        let idx = self.bounds.iter().position(|b| value < *b).unwrap_or(self.bounds.len());
+        self.counts[idx] += 1;
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// Aggregated performance and health counters for one evaluator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvaluatorStats {
+    pub engine: String,
+    pub calls: usize,
+    pub successes: usize,
+    pub failures: usize,
+    /// Summed wall time over all calls (seconds).
+    pub total_time: f64,
+    pub min_time: f64,
+    pub max_time: f64,
+    pub wall_time: Histogram,
+    /// Optimiser iterations of successful calls that reported them.
+    pub iterations: Histogram,
+}
+
+impl EvaluatorStats {
+    pub fn new(engine: impl Into<String>) -> Self {
+        Self {
+            engine: engine.into(),
+            calls: 0,
+            successes: 0,
+            failures: 0,
+            total_time: 0.0,
+            min_time: f64::INFINITY,
+            max_time: 0.0,
+            wall_time: Histogram::new(&TIME_BOUNDS),
+            iterations: Histogram::new(&ITER_BOUNDS),
+        }
+    }
+
+    pub fn mean_time(&self) -> f64 {
+        if self.calls == 0 { 0.0 } else { self.total_time / self.calls as f64 }
+    }
+
+    pub fn success_rate(&self) -> f64 {
+        if self.calls == 0 { 0.0 } else { self.successes as f64 / self.calls as f64 }
+    }
+
+    fn record(&mut self, seconds: f64, outcome: &Result<EvaluationResult>) {
+        self.calls += 1;
+        self.total_time += seconds;
+        self.min_time = self.min_time.min(seconds);
+        self.max_time = self.max_time.max(seconds);
+        self.wall_time.record(seconds);
+
+        match outcome {
+            Ok(res) => {
+                self.successes += 1;
+                if let Some(n) = res.iterations {
+                    self.iterations.record(n as f64);
+                }
+            }
+            Err(_) => self.failures += 1,
+        }
+    }
+}
+
+/// Wraps an evaluator and records wall time, outcome and iteration count of every call.
+/// Thread-safe: concurrent calls share one set of counters.
+pub struct InstrumentedEvaluator {
+    inner: Arc<dyn Evaluator>,
+    stats: Mutex<EvaluatorStats>,
+}
+
+impl InstrumentedEvaluator {
+    pub fn new(inner: Arc<dyn Evaluator>) -> Self {
+        let stats = Mutex::new(EvaluatorStats::new(inner.name()));
+        Self { inner, stats }
+    }
+}
+
+impl Evaluator for InstrumentedEvaluator {
+    fn name(&self) -> &str { self.inner.name() }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let start = Instant::now();
+        let outcome = self.inner.evaluate(cluster);
+        let seconds = start.elapsed().as_secs_f64();
+
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).record(seconds, &outcome);
+        outcome
+    }
+
+    fn stats(&self) -> Option<EvaluatorStats> {
+        Some(self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+}
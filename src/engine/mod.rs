@@ -1,3 +1,8 @@
 pub mod evaluator;
 pub mod external;
-pub mod operators;
\ No newline at end of file
+pub mod instrument;
+pub mod native;
+pub mod operators;
+pub mod properties;
+pub mod remote;
+pub mod screening;
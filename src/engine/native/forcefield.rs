@@ -0,0 +1,309 @@
+use std::sync::Arc;
+use anyhow::Result;
+use nalgebra::Vector3;
+
+use crate::core::domain::{Cluster, EvalFailure};
+use crate::core::spatial;
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+use crate::engine::native::potentials::PairPotential;
+
+/// Computes the total energy and per-atom forces of a cluster.
+/// Periodic clusters use the Minimum Image Convention for every pair.
+pub fn energy_forces(potential: &dyn PairPotential, cluster: &Cluster) -> (f64, Vec<Vector3<f64>>) {
+    let n = cluster.atoms.len();
+    let lattice = cluster.lattice.as_ref();
+    let cutoff_sq = potential.cutoff().powi(2);
+
+    let mut energy = 0.0;
+    let mut forces = vec![Vector3::zeros(); n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let a_i = &cluster.atoms[i];
+            let a_j = &cluster.atoms[j];
+            let d = spatial::displacement(&a_i.position, &a_j.position, lattice);
+            let r_sq = d.norm_squared();
+            if r_sq > cutoff_sq { continue; }
+
+            let r = r_sq.sqrt();
+            let (e, de) = potential.energy_deriv(a_i.element_id, a_j.element_id, r);
+            energy += e;
+
+            // F_i = -dV/dr_i = dV/dr * (r_j - r_i) / r
+            let f = d * (de / r);
+            forces[i] += f;
+            forces[j] -= f;
+        }
+    }
+
+    (energy, forces)
+}
+
+/// Settings for the FIRE local minimiser.
+#[derive(Debug, Clone)]
+pub struct RelaxSettings {
+    /// Hard cap on minimisation steps. A small value gives a "light" relaxation.
+    pub max_iterations: usize,
+    /// Convergence threshold on the largest per-atom force.
+    pub force_tolerance: f64,
+    /// Largest displacement any atom may make in one step (Å).
+    pub max_step: f64,
+    pub dt_start: f64,
+    pub dt_max: f64,
+    /// Any pair closer than this after relaxation is treated as a collapse.
+    pub collapse_distance: f64,
+}
+
+impl Default for RelaxSettings {
+    fn default() -> Self {
+        Self {
+            max_iterations: 2000,
+            force_tolerance: 1e-3,
+            max_step: 0.2,
+            dt_start: 0.05,
+            dt_max: 0.5,
+            collapse_distance: 0.5,
+        }
+    }
+}
+
+/// Outcome of a native relaxation.
+#[derive(Debug, Clone)]
+pub struct RelaxOutcome {
+    pub energy: f64,
+    pub max_force: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Relaxes `cluster` in place using FIRE (Bitzek et al., PRL 97, 170201).
+/// Fixed atoms (`is_fixed`) do not move. Unit masses are assumed.
+pub fn relax(potential: &dyn PairPotential, cluster: &mut Cluster, settings: &RelaxSettings) -> RelaxOutcome {
+    const N_MIN: usize = 5;
+    const F_INC: f64 = 1.1;
+    const F_DEC: f64 = 0.5;
+    const ALPHA_START: f64 = 0.1;
+    const F_ALPHA: f64 = 0.99;
+
+    let n = cluster.atoms.len();
+    let mut velocities = vec![Vector3::zeros(); n];
+    let mut dt = settings.dt_start;
+    let mut alpha = ALPHA_START;
+    let mut steps_since_reset = 0;
+
+    let (mut energy, mut forces) = energy_forces(potential, cluster);
+    let mut max_force = max_norm(&forces, cluster);
+    let mut iterations = 0;
+
+    while iterations < settings.max_iterations && max_force > settings.force_tolerance {
+        iterations += 1;
+
+        // 1. Mixing / restart based on the power P = F·v
+        let power: f64 = forces.iter().zip(&velocities).map(|(f, v)| f.dot(v)).sum();
+        if power > 0.0 {
+            let v_norm = velocities.iter().map(|v| v.norm_squared()).sum::<f64>().sqrt();
+            let f_norm = forces.iter().map(|f| f.norm_squared()).sum::<f64>().sqrt();
+            if f_norm > 0.0 {
+                for (v, f) in velocities.iter_mut().zip(&forces) {
+                    *v = *v * (1.0 - alpha) + f * (alpha * v_norm / f_norm);
+                }
+            }
+            if steps_since_reset > N_MIN {
+                dt = (dt * F_INC).min(settings.dt_max);
+                alpha *= F_ALPHA;
+            }
+            steps_since_reset += 1;
+        } else {
+            for v in &mut velocities { *v = Vector3::zeros(); }
+            dt *= F_DEC;
+            alpha = ALPHA_START;
+            steps_since_reset = 0;
+        }
+
+        // 2. Semi-implicit Euler step with per-atom displacement cap
+        for (i, atom) in cluster.atoms.iter_mut().enumerate() {
+            if atom.is_fixed { continue; }
+            velocities[i] += forces[i] * dt;
+            let mut dx = velocities[i] * dt;
+            let len = dx.norm();
+            if len > settings.max_step {
+                dx *= settings.max_step / len;
+            }
+            atom.position += dx;
+        }
+
+        let (e, f) = energy_forces(potential, cluster);
+        energy = e;
+        forces = f;
+        max_force = max_norm(&forces, cluster);
+
+        if !energy.is_finite() { break; }
+    }
+
+    for (atom, f) in cluster.atoms.iter_mut().zip(&forces) {
+        atom.force = *f;
+    }
+
+    RelaxOutcome {
+        energy,
+        max_force,
+        iterations,
+        converged: max_force <= settings.force_tolerance,
+    }
+}
+
+/// Settings for a short molecular-dynamics run (e.g. a minima-hopping escape).
+#[derive(Debug, Clone)]
+pub struct MdSettings {
+    pub timestep: f64,
+    /// Stop once the potential energy has passed through this many minima along the trajectory.
+    pub minima: usize,
+    /// Hard cap on integration steps.
+    pub max_steps: usize,
+}
+
+impl Default for MdSettings {
+    fn default() -> Self {
+        Self {
+            timestep: 0.01,
+            minima: 3,
+            max_steps: 5000,
+        }
+    }
+}
+
+/// Outcome of a native MD run.
+#[derive(Debug, Clone)]
+pub struct MdOutcome {
+    pub potential_energy: f64,
+    pub steps: usize,
+    pub minima: usize,
+}
+
+/// Velocity-Verlet trajectory starting from the atoms' current `velocity`.
+/// Fixed atoms (`is_fixed`) do not move. Unit masses are assumed, as in `relax`.
+/// Positions, velocities and forces are written back to `cluster`.
+pub fn molecular_dynamics(potential: &dyn PairPotential, cluster: &mut Cluster, settings: &MdSettings) -> MdOutcome {
+    let dt = settings.timestep;
+    let (mut energy, mut forces) = energy_forces(potential, cluster);
+    let mut descending = false;
+    let mut minima = 0;
+    let mut steps = 0;
+
+    while steps < settings.max_steps && minima < settings.minima {
+        steps += 1;
+
+        for (atom, f) in cluster.atoms.iter_mut().zip(&forces) {
+            if atom.is_fixed {
+                atom.velocity = Vector3::zeros();
+                continue;
+            }
+            atom.velocity += f * (0.5 * dt);
+            atom.position += atom.velocity * dt;
+        }
+
+        let (e, f) = energy_forces(potential, cluster);
+        for (atom, f) in cluster.atoms.iter_mut().zip(&f) {
+            if !atom.is_fixed {
+                atom.velocity += f * (0.5 * dt);
+            }
+        }
+
+        if !e.is_finite() {
+            energy = e;
+            break;
+        }
+
+        // A minimum along the trajectory: the potential energy turns from falling to rising
+        if descending && e > energy {
+            minima += 1;
+        }
+        descending = e < energy;
+        energy = e;
+        forces = f;
+    }
+
+    for (atom, f) in cluster.atoms.iter_mut().zip(&forces) {
+        atom.force = *f;
+    }
+
+    MdOutcome {
+        potential_energy: energy,
+        steps,
+        minima,
+    }
+}
+
+fn max_norm(forces: &[Vector3<f64>], cluster: &Cluster) -> f64 {
+    forces.iter()
+        .zip(&cluster.atoms)
+        .filter(|(_, a)| !a.is_fixed)
+        .map(|(f, _)| f.norm())
+        .fold(0.0, f64::max)
+}
+
+fn min_pair_distance(cluster: &Cluster) -> f64 {
+    let lattice = cluster.lattice.as_ref();
+    let mut min_sq = f64::MAX;
+    for i in 0..cluster.atoms.len() {
+        for j in (i + 1)..cluster.atoms.len() {
+            let d = spatial::distance_sq(&cluster.atoms[i].position, &cluster.atoms[j].position, lattice);
+            min_sq = min_sq.min(d);
+        }
+    }
+    min_sq.sqrt()
+}
+
+// --- Evaluator ---
+
+/// An in-process evaluator built on a `PairPotential`.
+/// Orders of magnitude faster than spawning GULP; useful for pre-screening and benchmarks.
+pub struct NativeEvaluator {
+    potential: Arc<dyn PairPotential>,
+    settings: RelaxSettings,
+    label: String,
+}
+
+impl NativeEvaluator {
+    pub fn new(potential: Arc<dyn PairPotential>) -> Self {
+        let label = format!("Native ({})", potential.name());
+        Self {
+            potential,
+            settings: RelaxSettings::default(),
+            label,
+        }
+    }
+
+    pub fn with_settings(mut self, settings: RelaxSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    pub fn potential(&self) -> &Arc<dyn PairPotential> {
+        &self.potential
+    }
+}
+
+impl Evaluator for NativeEvaluator {
+    fn name(&self) -> &str { &self.label }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let mut relaxed = cluster.clone();
+        let outcome = relax(self.potential.as_ref(), &mut relaxed, &self.settings);
+
+        if !outcome.energy.is_finite() || relaxed.atoms.iter().any(|a| a.position.coords.iter().any(|c| !c.is_finite())) {
+            return Err(EvalFailure::GeometricCollapse.into());
+        }
+        if relaxed.atoms.len() > 1 && min_pair_distance(&relaxed) < self.settings.collapse_distance {
+            return Err(EvalFailure::GeometricCollapse.into());
+        }
+
+        Ok(EvaluationResult {
+            energy: outcome.energy,
+            gradient_norm: Some(outcome.max_force),
+            iterations: Some(outcome.iterations),
+            relaxed_cluster: Some(relaxed),
+            properties: Default::default(),
+        })
+    }
+}
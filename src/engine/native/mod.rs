@@ -0,0 +1,2 @@
+pub mod forcefield;
+pub mod potentials;
\ No newline at end of file
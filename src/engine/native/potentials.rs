@@ -0,0 +1,204 @@
+/// Coulomb constant e^2 / (4 pi eps0) in eV·Å.
+pub const COULOMB_EV_ANGSTROM: f64 = 14.399645;
+
+/// A pairwise-additive interatomic potential.
+/// Species are addressed by the same `element_id` used in `Atom`.
+pub trait PairPotential: Send + Sync {
+    /// Returns `(V(r), dV/dr)` for a pair of species at separation `r`.
+    fn energy_deriv(&self, a: usize, b: usize, r: f64) -> (f64, f64);
+
+    /// Separation beyond which the pair interaction is ignored.
+    fn cutoff(&self) -> f64;
+
+    /// Short identifier used in evaluator names (e.g. "LJ").
+    fn name(&self) -> &str;
+}
+
+// --- Lennard-Jones ---
+
+/// Single-species 12-6 Lennard-Jones: `V = 4ε[(σ/r)^12 - (σ/r)^6]`.
+/// All species share the same parameters.
+#[derive(Debug, Clone)]
+pub struct LennardJones {
+    pub epsilon: f64,
+    pub sigma: f64,
+    pub cutoff: f64,
+}
+
+impl LennardJones {
+    pub fn new(epsilon: f64, sigma: f64) -> Self {
+        Self { epsilon, sigma, cutoff: f64::INFINITY }
+    }
+
+    /// Reduced units (ε = σ = 1), as used by the Cambridge Cluster Database.
+    pub fn reduced() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl PairPotential for LennardJones {
+    fn energy_deriv(&self, _a: usize, _b: usize, r: f64) -> (f64, f64) {
+        let sr6 = (self.sigma / r).powi(6);
+        let sr12 = sr6 * sr6;
+        let e = 4.0 * self.epsilon * (sr12 - sr6);
+        let de = 24.0 * self.epsilon * (sr6 - 2.0 * sr12) / r;
+        (e, de)
+    }
+
+    fn cutoff(&self) -> f64 { self.cutoff }
+
+    fn name(&self) -> &str { "LJ" }
+}
+
+// --- Morse ---
+
+/// Single-species Morse potential: `V = ε e^{ρ(1 - r/r0)} (e^{ρ(1 - r/r0)} - 2)`.
+/// `rho` sets the range: 6 is LJ-like, larger values give shorter-ranged, stickier clusters.
+#[derive(Debug, Clone)]
+pub struct Morse {
+    pub epsilon: f64,
+    pub r0: f64,
+    pub rho: f64,
+    pub cutoff: f64,
+}
+
+impl Morse {
+    pub fn new(epsilon: f64, r0: f64, rho: f64) -> Self {
+        Self { epsilon, r0, rho, cutoff: f64::INFINITY }
+    }
+
+    /// Reduced units (ε = r0 = 1), as used by the Cambridge Cluster Database.
+    pub fn reduced(rho: f64) -> Self {
+        Self::new(1.0, 1.0, rho)
+    }
+}
+
+impl PairPotential for Morse {
+    fn energy_deriv(&self, _a: usize, _b: usize, r: f64) -> (f64, f64) {
+        let x = (self.rho * (1.0 - r / self.r0)).exp();
+        let e = self.epsilon * x * (x - 2.0);
+        let de = -2.0 * self.epsilon * self.rho / self.r0 * x * (x - 1.0);
+        (e, de)
+    }
+
+    fn cutoff(&self) -> f64 { self.cutoff }
+
+    fn name(&self) -> &str { "Morse" }
+}
+
+// --- Dzugutov ---
+
+/// Dzugutov's glass-forming pair potential (PRA 46, R2984), in reduced units.
+/// A Lennard-Jones-like well plus a repulsive bump near √2 that frustrates
+/// close packing and favours icosahedral order. Smoothly zero beyond `b`.
+#[derive(Debug, Clone)]
+pub struct Dzugutov {
+    pub m: i32,
+    pub a_coef: f64,
+    pub c: f64,
+    pub a: f64,
+    pub b_coef: f64,
+    pub d: f64,
+    pub b: f64,
+}
+
+impl Default for Dzugutov {
+    fn default() -> Self {
+        Self { m: 16, a_coef: 5.82, c: 1.1, a: 1.87, b_coef: 1.28, d: 0.27, b: 1.94 }
+    }
+}
+
+impl PairPotential for Dzugutov {
+    fn energy_deriv(&self, _a: usize, _b: usize, r: f64) -> (f64, f64) {
+        let mut e = 0.0;
+        let mut de = 0.0;
+
+        if r < self.a {
+            let rm = r.powi(-self.m);
+            let damp = (self.c / (r - self.a)).exp();
+            e += self.a_coef * (rm - self.b_coef) * damp;
+            de += self.a_coef * damp * (-(self.m as f64) * rm / r - (rm - self.b_coef) * self.c / (r - self.a).powi(2));
+        }
+        if r < self.b {
+            let bump = self.b_coef * (self.d / (r - self.b)).exp();
+            e += bump;
+            de -= bump * self.d / (r - self.b).powi(2);
+        }
+
+        (e, de)
+    }
+
+    fn cutoff(&self) -> f64 { self.b }
+
+    fn name(&self) -> &str { "Dzugutov" }
+}
+
+// --- Buckingham + Coulomb ---
+
+/// Short-range Buckingham parameters for one species pair (GULP convention).
+#[derive(Debug, Clone, Copy)]
+pub struct BuckinghamTerm {
+    pub a: f64,   // eV
+    pub rho: f64, // Å
+    pub c: f64,   // eV·Å^6
+}
+
+/// Rigid-ion Buckingham model with bare Coulomb interactions:
+/// `V = A exp(-r/ρ) - C/r^6 + k q_i q_j / r`.
+///
+/// The Coulomb sum is direct (no Ewald), so this is only meaningful for 0D clusters.
+/// Intended as a fast pre-screening model, not a replacement for GULP.
+#[derive(Debug, Clone)]
+pub struct Buckingham {
+    num_species: usize,
+    terms: Vec<Option<BuckinghamTerm>>, // Flattened N x N, symmetric
+    charges: Vec<f64>,
+    pub cutoff: f64,
+}
+
+impl Buckingham {
+    /// `charges`: Formal charge per species (index = element_id).
+    pub fn new(charges: Vec<f64>, cutoff: f64) -> Self {
+        let n = charges.len();
+        Self {
+            num_species: n,
+            terms: vec![None; n * n],
+            charges,
+            cutoff,
+        }
+    }
+
+    /// Adds (or replaces) the short-range term for a species pair.
+    pub fn with_pair(mut self, i: usize, j: usize, a: f64, rho: f64, c: f64) -> Self {
+        let term = Some(BuckinghamTerm { a, rho, c });
+        self.terms[i * self.num_species + j] = term;
+        self.terms[j * self.num_species + i] = term;
+        self
+    }
+}
+
+impl PairPotential for Buckingham {
+    fn energy_deriv(&self, a: usize, b: usize, r: f64) -> (f64, f64) {
+        let mut e = 0.0;
+        let mut de = 0.0;
+
+        if let Some(t) = self.terms.get(a * self.num_species + b).copied().flatten() {
+            let rep = t.a * (-r / t.rho).exp();
+            let r6 = r.powi(6);
+            e += rep - t.c / r6;
+            de += -rep / t.rho + 6.0 * t.c / (r6 * r);
+        }
+
+        let qq = self.charges.get(a).copied().unwrap_or(0.0) * self.charges.get(b).copied().unwrap_or(0.0);
+        if qq != 0.0 {
+            e += COULOMB_EV_ANGSTROM * qq / r;
+            de -= COULOMB_EV_ANGSTROM * qq / (r * r);
+        }
+
+        (e, de)
+    }
+
+    fn cutoff(&self) -> f64 { self.cutoff }
+
+    fn name(&self) -> &str { "Buckingham" }
+}
@@ -13,6 +13,14 @@ pub struct Mutator {
     twist_intensity: Option<f64>,       // Max twist factor
     breathing_intensity: Option<f64>,   // Scaling factor range
     swap_count: Option<usize>,          // Pairs to swap
+    surface_intensity: Option<f64>,     // Max displacement of low-coordinated atoms
+    exchange_count: Option<usize>,      // Unlike-species pairs to swap
+}
+
+impl Default for Mutator {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Mutator {
@@ -24,6 +32,8 @@ impl Mutator {
             twist_intensity: None,
             breathing_intensity: None,
             swap_count: None,
+            surface_intensity: None,
+            exchange_count: None,
         }
     }
 
@@ -57,6 +67,18 @@ impl Mutator {
         self
     }
 
+    /// Displaces only the low-coordinated (surface) atoms; see `surface_atoms`.
+    pub fn surface_rattle(mut self, max_dist: f64) -> Self {
+        self.surface_intensity = Some(max_dist);
+        self
+    }
+
+    /// Swaps the positions of `count` pairs of atoms of different species.
+    pub fn exchange(mut self, count: usize) -> Self {
+        self.exchange_count = Some(count);
+        self
+    }
+
     pub fn apply(&self, cluster: &Cluster, rng: &mut impl Rng) -> Cluster {
         let mut c = cluster.clone();
         c.origin = "Mutation".to_string(); 
@@ -108,7 +130,17 @@ impl Mutator {
             }
         }
 
-        // 5. Swap
+        // 5. Surface Rattle
+        if let Some(mag) = self.surface_intensity {
+            for i in surface_atoms(&c) {
+                let atom = &mut c.atoms[i];
+                atom.position.x += rng.gen_range(-mag..mag);
+                atom.position.y += rng.gen_range(-mag..mag);
+                atom.position.z += rng.gen_range(-mag..mag);
+            }
+        }
+
+        // 6. Swap
         if let Some(count) = self.swap_count {
             let n = c.atoms.len();
             if n >= 2 {
@@ -124,7 +156,22 @@ impl Mutator {
             }
         }
 
-        // 6. Translation
+        // 7. Exchange (unlike species only, so every swap changes the configuration)
+        if let (Some(count), true) = (self.exchange_count, c.atoms.len() >= 2) {
+            for _ in 0..count {
+                let i = rng.gen_range(0..c.atoms.len());
+                let unlike: Vec<usize> = (0..c.atoms.len())
+                    .filter(|&j| c.atoms[j].element_id != c.atoms[i].element_id)
+                    .collect();
+                if let Some(&j) = unlike.choose(rng) {
+                    let tmp = c.atoms[i].position;
+                    c.atoms[i].position = c.atoms[j].position;
+                    c.atoms[j].position = tmp;
+                }
+            }
+        }
+
+        // 8. Translation
         if let Some(mag) = self.translation_intensity {
             let dx = rng.gen_range(-mag..mag);
             let dy = rng.gen_range(-mag..mag);
@@ -141,6 +188,70 @@ impl Mutator {
     }
 }
 
+/// Bond length, relative to the mean nearest-neighbour distance, used to count neighbours.
+const BOND_FACTOR: f64 = 1.3;
+
+/// Indices of the atoms with fewer neighbours than the cluster average. Falls back to
+/// every atom when all are equally coordinated.
+pub fn surface_atoms(cluster: &Cluster) -> Vec<usize> {
+    let cn = spatial::coordination_numbers(cluster, BOND_FACTOR);
+    if cn.is_empty() { return Vec::new(); }
+    let mean = cn.iter().sum::<usize>() as f64 / cn.len() as f64;
+    let low: Vec<usize> = (0..cn.len()).filter(|&i| (cn[i] as f64) < mean).collect();
+    if low.is_empty() { (0..cn.len()).collect() } else { low }
+}
+
+// --- Mutation Recipes ---
+
+/// Named mutation recipes the GA can choose between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MutationOp {
+    /// Rotate + rattle + one swap, with breathing 20% of the time.
+    Classic,
+    /// Rattle at twice the base displacement.
+    Rattle,
+    /// Twist about z plus a light rattle.
+    Twist,
+    /// Global scaling plus a light rattle.
+    Breathing,
+    /// Exchange the positions of two atom pairs.
+    Swap,
+}
+
+impl MutationOp {
+    pub const ALL: [MutationOp; 5] = [
+        MutationOp::Classic,
+        MutationOp::Rattle,
+        MutationOp::Twist,
+        MutationOp::Breathing,
+        MutationOp::Swap,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MutationOp::Classic => "Classic",
+            MutationOp::Rattle => "Rattle",
+            MutationOp::Twist => "Twist",
+            MutationOp::Breathing => "Breathing",
+            MutationOp::Swap => "Swap",
+        }
+    }
+
+    /// The mutator for this recipe; `rattle` is the base displacement (Å).
+    pub fn mutator(self, rattle: f64, rng: &mut impl Rng) -> Mutator {
+        match self {
+            MutationOp::Classic => {
+                let mutator = Mutator::new().rotate(0.5).rattle(rattle).swap(1);
+                if rng.gen_bool(0.2) { mutator.breathing(0.05) } else { mutator }
+            }
+            MutationOp::Rattle => Mutator::new().rattle(2.0 * rattle),
+            MutationOp::Twist => Mutator::new().twist(0.5).rattle(0.5 * rattle),
+            MutationOp::Breathing => Mutator::new().breathing(0.1).rattle(0.5 * rattle),
+            MutationOp::Swap => Mutator::new().swap(2),
+        }
+    }
+}
+
 // --- Helper for Crossover ---
 
 /// Rotates a set of atoms randomly around their geometric center.
@@ -158,7 +269,7 @@ fn apply_random_rotation<R: Rng + ?Sized>(atoms: &mut [Atom], rng: &mut R) {
     let axis = Unit::new_normalize(Vector3::new(
         rng.gen::<f64>() - 0.5, rng.gen::<f64>() - 0.5, rng.gen::<f64>() - 0.5
     ));
-    let angle = rng.gen_range(0.0..6.28);
+    let angle = rng.gen_range(0.0..std::f64::consts::TAU);
     let rot = Rotation3::from_axis_angle(&axis, angle);
     
     for a in atoms.iter_mut() { a.position = rot * a.position; }
@@ -176,7 +287,7 @@ pub fn crossover_cut_splice(p1: &Cluster, p2: &Cluster, rng: &mut impl Rng) -> O
     for a in &p1.atoms { target_counts[a.element_id] += 1; }
 
     let mut child = p1.clone();
-    child.origin = format!("X({},{})", p1.id.to_string()[0..4].to_string(), p2.id.to_string()[0..4].to_string());
+    child.origin = format!("X({},{})", &p1.id.to_string()[0..4], &p2.id.to_string()[0..4]);
     
     // 1. Prepare Parents (Clone -> Center -> Rotate)
     let mut p1_atoms = p1.atoms.clone();
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use anyhow::Result;
+use nalgebra::Vector3;
+
+use crate::core::domain::Cluster;
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+use crate::engine::instrument::EvaluatorStats;
+use crate::analysis::symmetry;
+
+/// Property key for the dipole magnitude (e·Å).
+pub const DIPOLE: &str = "dipole";
+/// Property key for the point-symmetry score (0 to 1).
+pub const SYMMETRY: &str = "symmetry";
+
+/// Tolerance (Å) for matching symmetry images in relaxed geometries.
+const SYMMETRY_TOLERANCE: f64 = 0.2;
+
+/// Adds structural properties of the relaxed geometry to every successful evaluation,
+/// for use as multi-objective GA objectives. Properties already reported by the inner
+/// evaluator are kept.
+pub struct PropertyEvaluator {
+    inner: Arc<dyn Evaluator>,
+    /// Formal charge per species (e), indexed by `element_id`.
+    charges: Vec<f64>,
+}
+
+impl PropertyEvaluator {
+    pub fn new(inner: Arc<dyn Evaluator>, charges: Vec<f64>) -> Self {
+        Self { inner, charges }
+    }
+}
+
+impl Evaluator for PropertyEvaluator {
+    fn name(&self) -> &str { self.inner.name() }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let mut res = self.inner.evaluate(cluster)?;
+        let geometry = res.relaxed_cluster.as_ref().unwrap_or(cluster);
+
+        let dipole = dipole_moment(geometry, &self.charges);
+        let score = symmetry::point_symmetry_score(geometry, SYMMETRY_TOLERANCE);
+        res.properties.entry(DIPOLE.to_string()).or_insert(dipole);
+        res.properties.entry(SYMMETRY.to_string()).or_insert(score);
+        Ok(res)
+    }
+
+    fn stats(&self) -> Option<EvaluatorStats> { self.inner.stats() }
+}
+
+/// Magnitude of the point-charge dipole about the geometric centre (e·Å).
+pub fn dipole_moment(cluster: &Cluster, charges: &[f64]) -> f64 {
+    if cluster.atoms.is_empty() { return 0.0; }
+    let centre = cluster.atoms.iter().map(|a| a.position.coords).sum::<Vector3<f64>>() / cluster.atoms.len() as f64;
+    cluster.atoms.iter()
+        .map(|a| charges.get(a.element_id).copied().unwrap_or(0.0) * (a.position.coords - centre))
+        .sum::<Vector3<f64>>()
+        .norm()
+}
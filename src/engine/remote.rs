@@ -0,0 +1,353 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::domain::{Cluster, EvalFailure};
+use crate::engine::evaluator::{Evaluator, EvaluationResult, classify_failure, failure};
+
+// --- Wire Protocol ---
+//
+// Newline-delimited JSON over TCP. Each connection carries one request at a time;
+// clients open several connections to the same worker for concurrency.
+
+/// Client -> worker message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum Request {
+    Evaluate { id: u64, cluster: Cluster },
+    Ping,
+}
+
+/// An evaluation error as seen by the worker, with its failure class preserved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteFailure {
+    pub kind: EvalFailure,
+    pub message: String,
+}
+
+/// Worker -> client message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum Response {
+    Evaluated { id: u64, outcome: Result<EvaluationResult, RemoteFailure> },
+    Pong { engine: String, capacity: usize },
+}
+
+fn send<T: Serialize>(stream: &mut TcpStream, msg: &T) -> Result<()> {
+    let mut line = serde_json::to_string(msg).context("Failed to encode message")?;
+    line.push('\n');
+    stream.write_all(line.as_bytes()).context("Failed to write to socket")?;
+    stream.flush().context("Failed to flush socket")?;
+    Ok(())
+}
+
+fn receive<T: for<'de> Deserialize<'de>>(reader: &mut BufReader<TcpStream>) -> Result<Option<T>> {
+    let mut line = String::new();
+    let n = reader.read_line(&mut line).context("Failed to read from socket")?;
+    if n == 0 {
+        return Ok(None); // Peer closed the connection
+    }
+    let msg = serde_json::from_str(line.trim_end()).context("Malformed message")?;
+    Ok(Some(msg))
+}
+
+// --- Worker (Server) ---
+
+/// Counting semaphore bounding concurrent evaluations on a worker.
+struct Gate {
+    free: Mutex<usize>,
+    cv: Condvar,
+}
+
+impl Gate {
+    fn acquire(&self) {
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        while *free == 0 {
+            free = self.cv.wait(free).unwrap_or_else(|e| e.into_inner());
+        }
+        *free -= 1;
+    }
+
+    fn release(&self) {
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        *free += 1;
+        self.cv.notify_one();
+    }
+}
+
+/// Serves `Evaluator` requests on `listener` until the listener fails.
+/// At most `max_concurrent` evaluations run at once; extra requests queue.
+pub fn serve(listener: TcpListener, evaluator: Arc<dyn Evaluator>, max_concurrent: usize) -> Result<()> {
+    let capacity = max_concurrent.max(1);
+    let gate = Arc::new(Gate { free: Mutex::new(capacity), cv: Condvar::new() });
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(s) => s,
+            Err(e) => {
+                log::warn!("Worker failed to accept connection: {}", e);
+                continue;
+            }
+        };
+        let evaluator = evaluator.clone();
+        let gate = gate.clone();
+        thread::Builder::new()
+            .name("Worker-Conn".to_string())
+            .spawn(move || {
+                if let Err(e) = handle_connection(stream, evaluator.as_ref(), &gate, capacity) {
+                    log::warn!("Worker connection closed: {}", e);
+                }
+            })
+            .context("Failed to spawn connection thread")?;
+    }
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, evaluator: &dyn Evaluator, gate: &Gate, capacity: usize) -> Result<()> {
+    let _ = stream.set_nodelay(true);
+    let mut writer = stream.try_clone().context("Failed to clone socket")?;
+    let mut reader = BufReader::new(stream);
+
+    while let Some(request) = receive::<Request>(&mut reader)? {
+        let response = match request {
+            Request::Ping => Response::Pong {
+                engine: evaluator.name().to_string(),
+                capacity,
+            },
+            Request::Evaluate { id, cluster } => {
+                gate.acquire();
+                let outcome = evaluator.evaluate(&cluster).map_err(|e| RemoteFailure {
+                    kind: classify_failure(&e),
+                    message: format!("{:#}", e),
+                });
+                gate.release();
+                Response::Evaluated { id, outcome }
+            }
+        };
+        send(&mut writer, &response)?;
+    }
+    Ok(())
+}
+
+// --- Client ---
+
+/// Connection and failover settings for `RemoteEvaluator`.
+#[derive(Debug, Clone)]
+pub struct RemoteSettings {
+    pub connect_timeout: Duration,
+    /// Upper bound on a single evaluation round-trip. `None` waits forever.
+    pub io_timeout: Option<Duration>,
+    /// Initial back-off after a worker fails; doubles on consecutive failures.
+    pub backoff: Duration,
+    pub max_backoff: Duration,
+    /// Transport failures tolerated per evaluation before giving up.
+    pub max_attempts: usize,
+}
+
+impl Default for RemoteSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            io_timeout: Some(Duration::from_secs(600)),
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: 8,
+        }
+    }
+}
+
+type Connection = BufReader<TcpStream>;
+
+struct WorkerState {
+    addr: String,
+    limit: usize,
+    in_flight: usize,
+    idle: Vec<Connection>,
+    failures: u32,
+    down_until: Option<Instant>,
+}
+
+/// Evaluator that forwards requests to `klmc worker` processes over TCP.
+///
+/// Requests are spread round-robin over workers with free slots. A transport failure
+/// marks the worker down (exponential back-off) and the request fails over to another
+/// worker; connections are re-established once the back-off expires.
+pub struct RemoteEvaluator {
+    pool: Mutex<Vec<WorkerState>>,
+    released: Condvar,
+    settings: RemoteSettings,
+    next_id: AtomicU64,
+    cursor: AtomicUsize,
+    label: String,
+}
+
+impl RemoteEvaluator {
+    /// `workers`: `(address, max concurrent jobs)` pairs, e.g. `("node1:7878", 8)`.
+    pub fn new(workers: Vec<(String, usize)>) -> Self {
+        let label = format!("Remote ({} workers)", workers.len());
+        let pool = workers.into_iter()
+            .map(|(addr, limit)| WorkerState {
+                addr,
+                limit: limit.max(1),
+                in_flight: 0,
+                idle: Vec::new(),
+                failures: 0,
+                down_until: None,
+            })
+            .collect();
+
+        Self {
+            pool: Mutex::new(pool),
+            released: Condvar::new(),
+            settings: RemoteSettings::default(),
+            next_id: AtomicU64::new(1),
+            cursor: AtomicUsize::new(0),
+            label,
+        }
+    }
+
+    pub fn with_settings(mut self, settings: RemoteSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Pings every worker, returning its engine name and capacity (or the error).
+    pub fn probe(&self) -> Vec<(String, Result<(String, usize)>)> {
+        let addrs: Vec<String> = self.lock_pool().iter().map(|w| w.addr.clone()).collect();
+        addrs.into_iter()
+            .map(|addr| {
+                let res = self.connect(&addr).and_then(|mut conn| {
+                    send(conn.get_mut(), &Request::Ping)?;
+                    match receive::<Response>(&mut conn)? {
+                        Some(Response::Pong { engine, capacity }) => Ok((engine, capacity)),
+                        Some(_) => bail!("Unexpected reply to ping"),
+                        None => bail!("Worker closed the connection"),
+                    }
+                });
+                (addr, res)
+            })
+            .collect()
+    }
+
+    fn lock_pool(&self) -> std::sync::MutexGuard<'_, Vec<WorkerState>> {
+        self.pool.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Blocks until some worker has a free slot. Returns its index and an idle connection, if any.
+    fn acquire(&self) -> Result<(usize, Option<Connection>)> {
+        let mut pool = self.lock_pool();
+        if pool.is_empty() {
+            bail!("No remote workers configured");
+        }
+
+        loop {
+            let now = Instant::now();
+            let n = pool.len();
+            let start = self.cursor.fetch_add(1, Ordering::Relaxed) % n;
+
+            for k in 0..n {
+                let i = (start + k) % n;
+                let w = &mut pool[i];
+                if w.down_until.is_some_and(|t| t > now) { continue; }
+                if w.in_flight >= w.limit { continue; }
+                w.in_flight += 1;
+                return Ok((i, w.idle.pop()));
+            }
+
+            // Sleep until a slot is released or the earliest back-off expires
+            let wait = pool.iter()
+                .filter_map(|w| w.down_until)
+                .filter(|t| *t > now)
+                .map(|t| t - now)
+                .min()
+                .unwrap_or(Duration::from_millis(100));
+            pool = self.released.wait_timeout(pool, wait).map(|(g, _)| g).unwrap_or_else(|e| e.into_inner().0);
+        }
+    }
+
+    fn release(&self, idx: usize, conn: Option<Connection>, failed: bool) {
+        let mut pool = self.lock_pool();
+        let w = &mut pool[idx];
+        w.in_flight = w.in_flight.saturating_sub(1);
+
+        if failed {
+            w.failures += 1;
+            let factor = 2u32.saturating_pow(w.failures.saturating_sub(1).min(16));
+            let backoff = self.settings.backoff.saturating_mul(factor).min(self.settings.max_backoff);
+            w.down_until = Some(Instant::now() + backoff);
+            w.idle.clear();
+        } else {
+            w.failures = 0;
+            w.down_until = None;
+            if let Some(c) = conn {
+                w.idle.push(c);
+            }
+        }
+        drop(pool);
+        self.released.notify_all();
+    }
+
+    fn connect(&self, addr: &str) -> Result<Connection> {
+        let sock = addr.to_socket_addrs()
+            .with_context(|| format!("Cannot resolve {}", addr))?
+            .next()
+            .ok_or_else(|| anyhow!("No address for {}", addr))?;
+        let stream = TcpStream::connect_timeout(&sock, self.settings.connect_timeout)
+            .with_context(|| format!("Cannot connect to {}", addr))?;
+        let _ = stream.set_nodelay(true);
+        stream.set_read_timeout(self.settings.io_timeout)?;
+        stream.set_write_timeout(self.settings.io_timeout)?;
+        Ok(BufReader::new(stream))
+    }
+
+    /// One request/response round-trip. Any error here is a transport failure.
+    fn exchange(&self, idx: usize, conn: Option<Connection>, request: &Request, id: u64) -> Result<(Connection, Result<EvaluationResult, RemoteFailure>)> {
+        let mut conn = match conn {
+            Some(c) => c,
+            None => {
+                let addr = self.lock_pool()[idx].addr.clone();
+                self.connect(&addr)?
+            }
+        };
+
+        send(conn.get_mut(), request)?;
+        match receive::<Response>(&mut conn)? {
+            Some(Response::Evaluated { id: rid, outcome }) if rid == id => Ok((conn, outcome)),
+            Some(_) => bail!("Protocol error: unexpected response"),
+            None => bail!("Worker closed the connection"),
+        }
+    }
+}
+
+impl Evaluator for RemoteEvaluator {
+    fn name(&self) -> &str { &self.label }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request = Request::Evaluate { id, cluster: cluster.clone() };
+        let mut last_err = anyhow!("No attempt made");
+
+        for _ in 0..self.settings.max_attempts.max(1) {
+            let (idx, conn) = self.acquire()?;
+            match self.exchange(idx, conn, &request, id) {
+                Ok((conn, outcome)) => {
+                    self.release(idx, Some(conn), false);
+                    // Evaluation errors are the engine's verdict, not a transport failure
+                    return outcome.map_err(|f| failure(f.kind, f.message));
+                }
+                Err(e) => {
+                    self.release(idx, None, true);
+                    log::warn!("Remote evaluation failed, retrying elsewhere: {:#}", e);
+                    last_err = e;
+                }
+            }
+        }
+
+        Err(failure(EvalFailure::Transport, format!("All remote evaluation attempts failed: {:#}", last_err)))
+    }
+}
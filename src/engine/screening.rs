@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use anyhow::Result;
+
+use crate::core::domain::Cluster;
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+
+/// How many rejected pre-relaxed energies are kept for post-run analysis.
+const REJECTED_LOG_CAPACITY: usize = 10_000;
+
+/// Decides which pre-relaxed candidates are worth an expensive evaluation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScreeningPolicy {
+    /// Forward if the cheap energy is within `window` eV of the lowest cheap energy seen so far.
+    EnergyWindow(f64),
+    /// Forward if the cheap energy ranks among the best `k` of the last `window` screened candidates.
+    /// Setting `window` to the population size approximates "top-k per generation".
+    TopK { k: usize, window: usize },
+}
+
+/// Error returned for candidates that did not pass the screening stage.
+/// Carries the cheap energy so callers can record it.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Screened out: pre-relaxed energy {cheap_energy:.4} eV above threshold {threshold:.4} eV")]
+pub struct ScreenedOut {
+    pub cheap_energy: f64,
+    pub threshold: f64,
+}
+
+/// Running counters for the screening stage.
+#[derive(Debug, Clone, Default)]
+pub struct ScreeningStats {
+    pub screened: usize,
+    pub forwarded: usize,
+    pub rejected: usize,
+    pub best_cheap_energy: Option<f64>,
+}
+
+#[derive(Default)]
+struct ScreeningHistory {
+    stats: ScreeningStats,
+    recent: VecDeque<f64>,
+    rejected_energies: VecDeque<f64>,
+}
+
+/// Two-stage evaluator: pre-relax with a fast model, then refine promising candidates
+/// with an expensive one (typically GULP).
+///
+/// Implements `Evaluator`, so solvers use it without modification. Rejected candidates
+/// surface as `ScreenedOut` errors and are discarded like any other failed evaluation.
+pub struct ScreeningEvaluator {
+    cheap: Arc<dyn Evaluator>,
+    expensive: Arc<dyn Evaluator>,
+    policy: ScreeningPolicy,
+    label: String,
+    history: Mutex<ScreeningHistory>,
+}
+
+impl ScreeningEvaluator {
+    pub fn new(cheap: Arc<dyn Evaluator>, expensive: Arc<dyn Evaluator>, policy: ScreeningPolicy) -> Self {
+        let label = format!("{} -> {}", cheap.name(), expensive.name());
+        Self {
+            cheap,
+            expensive,
+            policy,
+            label,
+            history: Mutex::new(ScreeningHistory::default()),
+        }
+    }
+
+    pub fn stats(&self) -> ScreeningStats {
+        self.history.lock().map(|h| h.stats.clone()).unwrap_or_default()
+    }
+
+    /// Cheap energies of the most recently rejected candidates (oldest first).
+    pub fn rejected_energies(&self) -> Vec<f64> {
+        self.history.lock()
+            .map(|h| h.rejected_energies.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Records a cheap energy and decides whether it passes the screen.
+    fn admit(&self, energy: f64) -> Result<(), ScreenedOut> {
+        let mut h = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        h.stats.screened += 1;
+
+        let threshold = match self.policy {
+            ScreeningPolicy::EnergyWindow(window) => {
+                let best = h.stats.best_cheap_energy.map_or(energy, |b| b.min(energy));
+                best + window
+            }
+            ScreeningPolicy::TopK { k, window } => {
+                h.recent.push_back(energy);
+                while h.recent.len() > window.max(1) {
+                    h.recent.pop_front();
+                }
+                if h.recent.len() <= k {
+                    f64::INFINITY
+                } else {
+                    let mut sorted: Vec<f64> = h.recent.iter().copied().collect();
+                    sorted.sort_by(|a, b| a.total_cmp(b));
+                    sorted[k.saturating_sub(1)]
+                }
+            }
+        };
+
+        if h.stats.best_cheap_energy.is_none_or(|b| energy < b) {
+            h.stats.best_cheap_energy = Some(energy);
+        }
+
+        if energy <= threshold {
+            h.stats.forwarded += 1;
+            Ok(())
+        } else {
+            h.stats.rejected += 1;
+            if h.rejected_energies.len() >= REJECTED_LOG_CAPACITY {
+                h.rejected_energies.pop_front();
+            }
+            h.rejected_energies.push_back(energy);
+            Err(ScreenedOut { cheap_energy: energy, threshold })
+        }
+    }
+}
+
+impl Evaluator for ScreeningEvaluator {
+    fn name(&self) -> &str { &self.label }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        // 1. Cheap pre-relaxation
+        let pre = self.cheap.evaluate(cluster)?;
+
+        // 2. Screen
+        self.admit(pre.energy)?;
+
+        // 3. Refine, starting from the pre-relaxed geometry when available
+        match &pre.relaxed_cluster {
+            Some(geom) if geom.atoms.len() == cluster.atoms.len() => self.expensive.evaluate(geom),
+            _ => self.expensive.evaluate(cluster),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
-use crossbeam_channel::{Receiver, TryRecvError};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use ratatui::widgets::TableState;
 
 use crate::core::domain::{Cluster, Params};
-use crate::solvers::{SolverEvent, GenStats};
+use crate::engine::instrument::EvaluatorStats;
+use crate::solvers::{SolverCommand, SolverEvent, Tuning, GenStats, FailureTally, ReplicaStats, BoxScanStats, OperatorStats, SpectrumEntry};
 
 // --- Constants ---
 const HISTORY_CAPACITY: usize = 1000;
@@ -41,12 +42,23 @@ pub struct Telemetry {
     pub avg_energy_history: VecDeque<(f64, f64)>,
     pub diversity_history: VecDeque<(f64, f64)>,   // (Iter, Diversity %)
     pub mutation_history: VecDeque<(f64, f64)>,    // (Iter, Rate)
+
+    // Latest adaptive move parameters (solvers that report them)
+    pub step_size: Option<f64>,
+    pub temperature: Option<f64>,
+    pub acceptance: Option<f64>,
     
     // Global Bounds for Chart Scaling
     pub global_min_energy: f64,
     pub global_max_energy: f64,
 }
 
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Telemetry {
     pub fn new() -> Self {
         Self {
@@ -54,6 +66,9 @@ impl Telemetry {
             avg_energy_history: VecDeque::with_capacity(HISTORY_CAPACITY),
             diversity_history: VecDeque::with_capacity(HISTORY_CAPACITY),
             mutation_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            step_size: None,
+            temperature: None,
+            acceptance: None,
             global_min_energy: f64::MAX,
             global_max_energy: f64::MIN,
         }
@@ -84,6 +99,9 @@ impl Telemetry {
         self.avg_energy_history.push_back((x, stats.avg_energy));
         self.diversity_history.push_back((x, stats.diversity * 100.0));
         self.mutation_history.push_back((x, stats.mutation_rate));
+        self.step_size = stats.step_size;
+        self.temperature = stats.temperature;
+        self.acceptance = stats.acceptance;
     }
 }
 
@@ -97,6 +115,12 @@ pub struct Viewport {
     pub last_tick: Instant,
 }
 
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Viewport {
     pub fn new() -> Self {
         Self {
@@ -120,6 +144,60 @@ impl Viewport {
     }
 }
 
+// --- Live Tuning ---
+
+/// Editable copy of the settings a running solver accepts (Analysis tab).
+/// Values are edited locally and only sent when applied.
+#[derive(Debug, Clone)]
+pub struct TuningPanel {
+    pub selected: usize,
+    pub mutation_rate: f64,
+    pub temperature: f64,
+    pub step_size: f64,
+}
+
+impl TuningPanel {
+    pub const LABELS: [&'static str; 4] = ["Mutation Rate:", "Temperature:", "Step Size:", "Extinction"];
+
+    pub fn new(params: &Params) -> Self {
+        Self {
+            selected: 0,
+            mutation_rate: params.mutation_rate,
+            temperature: params.temperature,
+            step_size: params.step_size,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % Self::LABELS.len();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = (self.selected + Self::LABELS.len() - 1) % Self::LABELS.len();
+    }
+
+    /// Nudges the selected value up or down by one increment.
+    pub fn adjust(&mut self, up: bool) {
+        let sign = if up { 1.0 } else { -1.0 };
+        match self.selected {
+            0 => self.mutation_rate = (self.mutation_rate + sign * 0.05).clamp(0.0, 1.0),
+            1 => self.temperature = (self.temperature + sign * 50.0).max(0.0),
+            2 => self.step_size = (self.step_size + sign * 0.05).max(0.05),
+            _ => {}
+        }
+    }
+
+    /// The command for the selected row.
+    pub fn tuning(&self) -> Tuning {
+        match self.selected {
+            0 => Tuning::MutationRate(self.mutation_rate),
+            1 => Tuning::Temperature(self.temperature),
+            2 => Tuning::StepSize(self.step_size),
+            _ => Tuning::Extinction,
+        }
+    }
+}
+
 // --- The Master State ---
 
 pub struct AppState {
@@ -130,6 +208,8 @@ pub struct AppState {
     
     // Worker
     pub rx: Option<Receiver<SolverEvent>>, 
+    /// Command channel to the solver thread (`None` if the solver cannot be controlled).
+    pub commands: Option<Sender<SolverCommand>>,
     pub worker_status: WorkerStatus,
     
     // Simulation Data
@@ -137,14 +217,23 @@ pub struct AppState {
     pub start_time: Instant,
     pub current_best: Option<Cluster>,
     pub hall_of_fame: Vec<Cluster>, 
+    /// True once a multi-objective run has replaced the Hall of Fame with its Pareto front.
+    pub pareto: bool,
     pub active_cluster: Option<Cluster>, 
     
     // Analytics
     pub telemetry: Telemetry,
+    pub failures: FailureTally,
+    pub evaluator_stats: Option<EvaluatorStats>,
+    pub replicas: Vec<ReplicaStats>,
+    pub box_scan: Vec<BoxScanStats>,
+    pub spectrum: Vec<SpectrumEntry>,
+    pub operators: Vec<OperatorStats>,
     pub logs: VecDeque<String>,
     
     // UI Elements
     pub hof_state: TableState,
+    pub tuning: TuningPanel,
     pub viewport: Viewport,
     
     // Performance Metrics
@@ -157,20 +246,30 @@ impl AppState {
     pub fn new(default_params: Params) -> Self {
         let mut hof_state = TableState::default();
         hof_state.select(Some(0));
+        let tuning = TuningPanel::new(&default_params);
 
         Self {
             should_quit: false,
             mode: AppMode::Dashboard,
             params: default_params,
             rx: None,
+            commands: None,
             worker_status: WorkerStatus::Idle,
             total_iterations: 0,
             start_time: Instant::now(),
             current_best: None,
             hall_of_fame: Vec::with_capacity(HOF_CAPACITY),
+            pareto: false,
             active_cluster: None,
             telemetry: Telemetry::new(),
+            failures: FailureTally::default(),
+            evaluator_stats: None,
+            replicas: Vec::new(),
+            box_scan: Vec::new(),
+            spectrum: Vec::new(),
+            operators: Vec::new(),
             logs: VecDeque::with_capacity(LOG_CAPACITY),
+            tuning,
             hof_state,
             viewport: Viewport::new(),
             ops_counter: 0,
@@ -185,6 +284,10 @@ impl AppState {
         self.start_time = Instant::now();
     }
 
+    pub fn set_control(&mut self, commands: Sender<SolverCommand>) {
+        self.commands = Some(commands);
+    }
+
     pub fn tick(&mut self) {
         self.viewport.tick();
         self.calc_metrics();
@@ -211,28 +314,82 @@ impl AppState {
             SolverEvent::Log(msg) => self.log(msg),
             
             SolverEvent::WorkerHeartbeat(ops) => {
-                self.worker_status = WorkerStatus::Running;
+                self.mark_running();
                 if ops > 0.0 { self.ops_per_second = ops; }
             },
 
             SolverEvent::GenerationUpdate(stats) => {
-                self.worker_status = WorkerStatus::Running;
+                self.mark_running();
                 self.total_iterations = stats.generation;
                 self.ops_counter += stats.valid_count;
                 self.telemetry.ingest(&stats);
+                self.failures = stats.failures;
+            },
+
+            SolverEvent::EvaluatorStats(stats) => {
+                self.evaluator_stats = Some(stats);
+            },
+
+            SolverEvent::ReplicaUpdate(replicas) => {
+                self.replicas = replicas;
+            },
+
+            SolverEvent::BoxScanUpdate(scan) => {
+                self.box_scan = scan;
+            },
+
+            SolverEvent::SpectrumUpdate(spectrum) => {
+                self.spectrum = spectrum;
+            },
+
+            SolverEvent::OperatorUpdate(operators) => {
+                self.operators = operators;
+            },
+
+            SolverEvent::ParetoUpdate(front) => {
+                self.pareto = true;
+                self.hall_of_fame = front;
+                if self.hof_state.selected().is_some_and(|i| i >= self.hall_of_fame.len()) {
+                    self.hof_state.select(Some(0));
+                }
             },
 
             SolverEvent::NewBest(cluster) => {
                 self.handle_new_best(cluster);
             },
 
-            SolverEvent::Finished => {
+            SolverEvent::Tuned(tuning) => match tuning {
+                Tuning::MutationRate(rate) => self.params.mutation_rate = rate,
+                Tuning::Temperature(t) => self.params.temperature = t,
+                Tuning::StepSize(step) => self.params.step_size = step,
+                Tuning::Extinction => {}
+            },
+
+            SolverEvent::Snapshot(state) => {
+                let energy = state.best.as_ref().and_then(|c| c.energy);
+                self.log(format!(
+                    "Snapshot at step {}: best {}",
+                    state.step, energy.map_or("-".to_string(), |e| format!("{:.5} eV", e))
+                ));
+                if let Some(best) = state.best {
+                    self.active_cluster = Some(best);
+                }
+            },
+
+            SolverEvent::Finished(reason) => {
                 self.worker_status = WorkerStatus::Finished;
-                self.log("Solver finished.");
+                self.log(format!("Solver finished: {}.", reason));
             }
         }
     }
 
+    /// Events still arriving from a step in progress do not undo a pause.
+    fn mark_running(&mut self) {
+        if self.worker_status != WorkerStatus::Paused {
+            self.worker_status = WorkerStatus::Running;
+        }
+    }
+
     fn handle_new_best(&mut self, cluster: Cluster) {
         let e_new = cluster.energy.unwrap_or(0.0);
         
@@ -247,7 +404,11 @@ impl AppState {
             self.log(format!(">>> New Global Record: {:.5} eV", e_new));
         }
 
-        // 2. Hall of Fame Deduplication (Isomer Check)
+        // 2. Hall of Fame Deduplication (Isomer Check); a Pareto front is maintained by the solver
+        if self.pareto {
+            self.active_cluster = Some(cluster);
+            return;
+        }
         let mut replaced = false;
         
         let new_hash = cluster.hash_key.as_deref().unwrap_or("INVALID");
@@ -313,7 +474,14 @@ impl AppState {
             '3' => self.mode = AppMode::HallOfFame,
             '4' => self.mode = AppMode::StructureViewer,
             ' ' => self.toggle_pause(),
+            's' => self.request_snapshot(),
+            'x' => self.stop(),
             'r' => self.viewport.azimuth = 0.0,
+            'j' if self.mode == AppMode::Analysis => self.tuning.select_next(),
+            'k' if self.mode == AppMode::Analysis => self.tuning.select_prev(),
+            '+' | '=' if self.mode == AppMode::Analysis => self.tuning.adjust(true),
+            '-' if self.mode == AppMode::Analysis => self.tuning.adjust(false),
+            'a' if self.mode == AppMode::Analysis => self.apply_tuning(),
             'j' => self.select_next_hof(),
             'k' => self.select_prev_hof(),
             _ => {}
@@ -343,14 +511,47 @@ impl AppState {
     pub fn toggle_pause(&mut self) {
         match self.worker_status {
             WorkerStatus::Running => {
+                self.send(SolverCommand::Pause);
                 self.worker_status = WorkerStatus::Paused;
                 self.log("Paused.");
             },
             WorkerStatus::Paused => {
+                self.send(SolverCommand::Resume);
                 self.worker_status = WorkerStatus::Running;
                 self.log("Resumed.");
             },
             _ => {}
         }
     }
-}
\ No newline at end of file
+
+    /// Asks the solver to end the run; it still sends its final results.
+    pub fn stop(&mut self) {
+        if matches!(self.worker_status, WorkerStatus::Starting | WorkerStatus::Running | WorkerStatus::Paused) {
+            self.send(SolverCommand::Stop);
+            self.log("Stopping after the current evaluations...");
+        }
+    }
+
+    /// Sends the selected row of the tuning panel to the solver.
+    fn apply_tuning(&mut self) {
+        if self.commands.is_none() {
+            self.log("No running solver to tune.");
+            return;
+        }
+        let tuning = self.tuning.tuning();
+        self.send(SolverCommand::Tune(tuning));
+        self.log(format!("Requested {} change.", tuning.name()));
+    }
+
+    fn request_snapshot(&mut self) {
+        self.send(SolverCommand::Snapshot);
+    }
+
+    fn send(&mut self, cmd: SolverCommand) {
+        if let Some(commands) = &self.commands {
+            if commands.send(cmd).is_err() {
+                self.commands = None;
+            }
+        }
+    }
+}
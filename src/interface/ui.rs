@@ -3,13 +3,16 @@ use ratatui::{
     widgets::{
         Block, Borders, BorderType, Paragraph, Tabs, Gauge, 
         Sparkline, Table, Row, Cell, Wrap, ListItem, List,
+        Axis, Chart, Dataset, GraphType,
         canvas::{Canvas, Circle, Line as CanvasLine},
     },
     style::{Color, Style, Modifier},
+    symbols::Marker,
     text::{Line, Span},
 };
-use crate::interface::state::{AppState, AppMode, WorkerStatus};
+use crate::interface::state::{AppState, AppMode, TuningPanel, WorkerStatus};
 use crate::core::domain::Cluster;
+use crate::engine::instrument::Histogram;
 
 // --- Color Palette ---
 const COL_BG: Color = Color::Reset;
@@ -100,7 +103,7 @@ fn draw_footer(f: &mut Frame, app: &AppState, area: Rect) {
         Span::raw(format!("Ops/s: {:<6.1}", app.ops_per_second)),
         Span::raw(" | "),
         Span::styled(format!("Best: {:.4} eV", best_val), Style::default().fg(COL_ACCENT)),
-        Span::raw(" | [Q]uit [Space]Pause [R]eset-View"),
+        Span::raw(" | [Q]uit [Space]Pause [X]Stop [S]napshot [R]eset-View"),
     ]);
 
     let p = Paragraph::new(text)
@@ -328,7 +331,7 @@ fn draw_stats(f: &mut Frame, app: &AppState, area: Rect) {
     f.render_widget(block, area);
 
     // FIXED: Use correct global_min_energy field
-    let text = vec![
+    let mut text = vec![
         Line::from(vec![
             Span::styled("Algorithm: ", Style::default().fg(Color::Gray)),
             Span::styled(format!("{:?}", app.params.algorithm), Style::default().fg(COL_HIGHLIGHT))
@@ -346,14 +349,33 @@ fn draw_stats(f: &mut Frame, app: &AppState, area: Rect) {
             Span::styled(format!("{:.5} eV", app.telemetry.global_min_energy), Style::default().fg(COL_SUCCESS).add_modifier(Modifier::BOLD))
         ]),
     ];
+    if let Some(step) = app.telemetry.step_size {
+        text.push(Line::from(vec![
+            Span::styled("Step Size: ", Style::default().fg(Color::Gray)),
+            Span::styled(format!("{:.3} Å", step), Style::default().fg(COL_HIGHLIGHT))
+        ]));
+    }
+    if let Some(t) = app.telemetry.temperature {
+        text.push(Line::from(vec![
+            Span::styled("Temperature: ", Style::default().fg(Color::Gray)),
+            Span::styled(format!("{:.1} K", t), Style::default().fg(COL_HIGHLIGHT))
+        ]));
+    }
+    if let Some(a) = app.telemetry.acceptance {
+        text.push(Line::from(vec![
+            Span::styled("Acceptance: ", Style::default().fg(Color::Gray)),
+            Span::styled(format!("{:.0}%", a * 100.0), Style::default().fg(COL_HIGHLIGHT))
+        ]));
+    }
 
     f.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), inner);
 }
 
 fn draw_hall_of_fame(f: &mut Frame, app: &mut AppState, area: Rect) {
-    let header_cells = ["Rank", "ID", "Energy (eV)", "Origin", "Hash"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(COL_HEADER)));
+    let last_column = if app.pareto { "Objectives" } else { "Hash" };
+    let header_cells = ["Rank", "ID", "Energy (eV)", "Origin", last_column]
+        .into_iter()
+        .map(|h| Cell::from(h).style(Style::default().fg(COL_HEADER)));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
 
     if app.hall_of_fame.is_empty() {
@@ -371,13 +393,21 @@ fn draw_hall_of_fame(f: &mut Frame, app: &mut AppState, area: Rect) {
         let id_disp = if id_short.len() > 8 { &id_short[..8] } else { &id_short };
         let hash_disp = c.hash_key.as_deref().unwrap_or("-");
         let hash_short = if hash_disp.len() > 12 { &hash_disp[..12] } else { hash_disp };
+        let last = if app.pareto {
+            app.params.objectives.iter()
+                .map(|o| format!("{} {}", o.property, o.value(c).map_or("-".to_string(), |v| format!("{:.3}", v))))
+                .collect::<Vec<_>>()
+                .join("  ")
+        } else {
+            hash_short.to_string()
+        };
 
         let cells = vec![
             Cell::from(format!("#{}", i + 1)),
             Cell::from(id_disp.to_string()),
             Cell::from(format!("{:.5}", c.energy.unwrap_or(0.0))),
             Cell::from(c.origin.clone()),
-            Cell::from(hash_short.to_string()),
+            Cell::from(last),
         ];
         Row::new(cells).height(1)
     });
@@ -390,22 +420,356 @@ fn draw_hall_of_fame(f: &mut Frame, app: &mut AppState, area: Rect) {
         Constraint::Min(10),
     ])
     .header(header)
-    .block(Block::default().borders(Borders::ALL).title(format!(" Hall of Fame ({}) ", app.hall_of_fame.len())))
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        " {} ({}) ",
+        if app.pareto { "Pareto Front" } else { "Hall of Fame" },
+        app.hall_of_fame.len()
+    )))
     .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
     f.render_stateful_widget(t, area, &mut app.hof_state);
 }
 
 fn draw_analysis(f: &mut Frame, app: &AppState, area: Rect) {
-    draw_config(f, app, area);
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(cols[0]);
+
+    draw_config(f, app, left[0]);
+    draw_failures(f, app, left[1]);
+
+    if !app.replicas.is_empty() {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(app.replicas.len() as u16 + 3)])
+            .split(cols[1]);
+        draw_evaluator(f, app, right[0]);
+        draw_replicas(f, app, right[1]);
+    } else if !app.box_scan.is_empty() {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(app.box_scan.len() as u16 + 3)])
+            .split(cols[1]);
+        draw_evaluator(f, app, right[0]);
+        draw_box_scan(f, app, right[1]);
+    } else if !app.spectrum.is_empty() {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(cols[1]);
+        draw_evaluator(f, app, right[0]);
+        draw_spectrum(f, app, right[1]);
+    } else if app.pareto && app.params.objectives.len() >= 2 {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(cols[1]);
+        draw_evaluator(f, app, right[0]);
+        draw_pareto(f, app, right[1]);
+    } else if !app.operators.is_empty() {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(app.operators.len() as u16 + 3)])
+            .split(cols[1]);
+        draw_evaluator(f, app, right[0]);
+        draw_operators(f, app, right[1]);
+    } else {
+        draw_evaluator(f, app, cols[1]);
+    }
+}
+
+/// Pareto front projected onto the first two objectives; the selected Hall of Fame entry is highlighted.
+fn draw_pareto(f: &mut Frame, app: &AppState, area: Rect) {
+    let (ox, oy) = (&app.params.objectives[0], &app.params.objectives[1]);
+    let points: Vec<(f64, f64)> = app.hall_of_fame.iter()
+        .filter_map(|c| Some((ox.value(c)?, oy.value(c)?)))
+        .collect();
+    let selected: Vec<(f64, f64)> = app.hof_state.selected()
+        .and_then(|i| app.hall_of_fame.get(i))
+        .and_then(|c| Some((ox.value(c)?, oy.value(c)?)))
+        .into_iter()
+        .collect();
+
+    let bounds = |values: Vec<f64>| {
+        let lo = values.iter().cloned().fold(f64::MAX, f64::min);
+        let hi = values.iter().cloned().fold(f64::MIN, f64::max);
+        if lo > hi { [0.0, 1.0] } else { let pad = ((hi - lo) * 0.05).max(1e-3); [lo - pad, hi + pad] }
+    };
+    let xb = bounds(points.iter().map(|p| p.0).collect());
+    let yb = bounds(points.iter().map(|p| p.1).collect());
+    let labels = |b: [f64; 2]| vec![Span::raw(format!("{:.3}", b[0])), Span::raw(format!("{:.3}", b[1]))];
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(COL_ACCENT))
+            .data(&points),
+        Dataset::default()
+            .marker(Marker::Block)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(COL_HIGHLIGHT))
+            .data(&selected),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Pareto Front ({}) ", points.len())))
+        .x_axis(Axis::default().title(ox.label()).bounds(xb).labels(labels(xb)).style(Style::default().fg(COL_FG)))
+        .y_axis(Axis::default().title(oy.label()).bounds(yb).labels(labels(yb)).style(Style::default().fg(COL_FG)));
+
+    f.render_widget(chart, area);
+}
+
+/// Learned GA mutation operator probabilities; the highest is highlighted.
+fn draw_operators(f: &mut Frame, app: &AppState, area: Rect) {
+    const BAR_WIDTH: usize = 12;
+    let top = app.operators.iter().map(|o| o.probability).fold(0.0, f64::max);
+
+    let header = Row::new(vec!["Operator", "P", "Quality", "Success", ""])
+        .style(Style::default().fg(COL_HEADER).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app.operators.iter().map(|o| {
+        let color = if o.probability >= top { COL_SUCCESS } else { COL_ACCENT };
+        Row::new(vec![
+            Cell::from(o.name.clone()),
+            Cell::from(format!("{:.0}%", o.probability * 100.0)),
+            Cell::from(format!("{:.2}", o.quality)),
+            Cell::from(format!("{}/{}", o.successes, o.uses)),
+            Cell::from(Span::styled("█".repeat((o.probability * BAR_WIDTH as f64).round() as usize), Style::default().fg(color))),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(BAR_WIDTH as u16),
+    ])
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(" Mutation Operators "));
+
+    f.render_widget(table, area);
+}
+
+/// Distinct minima of a random search, lowest first, with how often each was hit.
+fn draw_spectrum(f: &mut Frame, app: &AppState, area: Rect) {
+    const BAR_WIDTH: usize = 12;
+    let lowest = app.spectrum.first().map(|m| m.energy).unwrap_or(0.0);
+    let peak = app.spectrum.iter().map(|m| m.hits).max().unwrap_or(0).max(1);
+
+    let header = Row::new(vec!["#", "Energy (eV)", "ΔE (eV)", "Hits", ""])
+        .style(Style::default().fg(COL_HEADER).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app.spectrum.iter().enumerate().map(|(i, m)| {
+        Row::new(vec![
+            Cell::from(format!("{}", i + 1)),
+            Cell::from(format!("{:.4}", m.energy)),
+            Cell::from(format!("{:.4}", m.energy - lowest)),
+            Cell::from(m.hits.to_string()),
+            Cell::from(Span::styled("█".repeat(m.hits * BAR_WIDTH / peak), Style::default().fg(COL_ACCENT))),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(4),
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Length(BAR_WIDTH as u16),
+    ])
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(" Minima Spectrum "));
+
+    f.render_widget(table, area);
+}
+
+/// Energy vs box size. The bar shows how deep each box's best lies between the
+/// shallowest and deepest best energies of the scan.
+fn draw_box_scan(f: &mut Frame, app: &AppState, area: Rect) {
+    const BAR_WIDTH: usize = 12;
+    let bests: Vec<f64> = app.box_scan.iter().filter_map(|s| s.best_energy).collect();
+    let lo = bests.iter().cloned().fold(f64::MAX, f64::min);
+    let hi = bests.iter().cloned().fold(f64::MIN, f64::max);
+    let fmt = |e: Option<f64>| e.map(|e| format!("{:.4}", e)).unwrap_or_else(|| "-".to_string());
+
+    let header = Row::new(vec!["Box (Å)", "Valid", "Best (eV)", "Mean (eV)", ""])
+        .style(Style::default().fg(COL_HEADER).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app.box_scan.iter().map(|s| {
+        let depth = match s.best_energy {
+            Some(e) if hi > lo => 1 + ((hi - e) / (hi - lo) * (BAR_WIDTH - 1) as f64) as usize,
+            Some(_) => BAR_WIDTH,
+            None => 0,
+        };
+        let color = if s.best_energy.is_some() && s.best_energy == Some(lo) { COL_SUCCESS } else { COL_ACCENT };
+        Row::new(vec![
+            Cell::from(format!("{:.2}", s.box_size)),
+            Cell::from(format!("{}/{}", s.valid, s.samples)),
+            Cell::from(fmt(s.best_energy)),
+            Cell::from(fmt(s.mean_energy)),
+            Cell::from(Span::styled("█".repeat(depth), Style::default().fg(color))),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(BAR_WIDTH as u16),
+    ])
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(" Box Scan "));
+
+    f.render_widget(table, area);
+}
+
+fn draw_replicas(f: &mut Frame, app: &AppState, area: Rect) {
+    let header = Row::new(vec!["T (K)", "Energy (eV)", "Accept", "Swap"])
+        .style(Style::default().fg(COL_HEADER).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app.replicas.iter().map(|r| {
+        Row::new(vec![
+            Cell::from(format!("{:.0}", r.temperature)),
+            Cell::from(format!("{:.4}", r.energy)),
+            Cell::from(format!("{:.0}%", r.acceptance_rate * 100.0)),
+            Cell::from(format!("{:.0}%", r.swap_rate * 100.0)),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(8),
+        Constraint::Length(14),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ])
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(" Replicas "));
+
+    f.render_widget(table, area);
+}
+
+fn draw_evaluator(f: &mut Frame, app: &AppState, area: Rect) {
+    let Some(stats) = &app.evaluator_stats else {
+        let block = Block::default().borders(Borders::ALL).title(" Evaluator ");
+        let p = Paragraph::new("No evaluator statistics yet.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let block = Block::default().borders(Borders::ALL).title(format!(" Evaluator: {} ", stats.engine));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let kv = |k: &str, v: String| -> ListItem {
+        ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<15}", k), Style::default().fg(COL_ACCENT)),
+            Span::raw(v)
+        ]))
+    };
+
+    let rate_color = if stats.success_rate() >= 0.5 { COL_SUCCESS } else { COL_FAIL };
+    let mut items = vec![
+        kv("Calls:", stats.calls.to_string()),
+        ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<15}", "Success:"), Style::default().fg(COL_ACCENT)),
+            Span::styled(
+                format!("{} ({:.1}%)", stats.successes, stats.success_rate() * 100.0),
+                Style::default().fg(rate_color),
+            ),
+            Span::raw(format!("  failed {}", stats.failures)),
+        ])),
+        kv("Wall Time:", format!(
+            "mean {}  min {}  max {}",
+            fmt_seconds(stats.mean_time()),
+            fmt_seconds(if stats.calls > 0 { stats.min_time } else { 0.0 }),
+            fmt_seconds(stats.max_time),
+        )),
+        ListItem::new(Line::from(" ")),
+        ListItem::new(Line::from(Span::styled("Wall Time per Call", Style::default().fg(COL_HEADER)))),
+    ];
+
+    let bar_width = inner.width.saturating_sub(20) as usize;
+    items.extend(histogram_rows(&stats.wall_time, fmt_seconds, bar_width, COL_ACCENT));
+    items.push(ListItem::new(Line::from(" ")));
+    items.push(ListItem::new(Line::from(Span::styled(
+        format!("Optimiser Iterations ({} reported)", stats.iterations.total()),
+        Style::default().fg(COL_HEADER),
+    ))));
+    items.extend(histogram_rows(&stats.iterations, |b| format!("{}", b), bar_width, COL_DIVERSITY));
+
+    f.render_widget(List::new(items), inner);
+}
+
+/// One row per bucket: upper-bound label, count and a bar scaled to the fullest bucket.
+fn histogram_rows(hist: &Histogram, label: fn(f64) -> String, bar_width: usize, color: Color) -> Vec<ListItem<'static>> {
+    let peak = hist.counts.iter().copied().max().unwrap_or(0).max(1);
+    hist.counts.iter().enumerate().map(|(i, &n)| {
+        let name = match hist.bounds.get(i) {
+            Some(b) => format!("< {}", label(*b)),
+            None => format!(">= {}", label(hist.bounds.last().copied().unwrap_or(0.0))),
+        };
+        let bar = "█".repeat(n * bar_width / peak);
+        ListItem::new(Line::from(vec![
+            Span::raw(format!("{:>9} {:>6} ", name, n)),
+            Span::styled(bar, Style::default().fg(color)),
+        ]))
+    }).collect()
+}
+
+fn fmt_seconds(s: f64) -> String {
+    if s < 1.0 {
+        format!("{:.0}ms", s * 1000.0)
+    } else if s < 60.0 {
+        format!("{:.1}s", s)
+    } else {
+        format!("{:.0}m", s / 60.0)
+    }
+}
+
+fn draw_failures(f: &mut Frame, app: &AppState, area: Rect) {
+    let total = app.failures.total();
+    let block = Block::default().borders(Borders::ALL).title(format!(" Rejected Candidates ({}) ", total));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if total == 0 {
+        f.render_widget(Paragraph::new("No failures recorded.").style(Style::default().fg(Color::DarkGray)), inner);
+        return;
+    }
+
+    let bar_width = inner.width.saturating_sub(34) as usize;
+    let items: Vec<ListItem> = app.failures.iter().map(|(kind, n)| {
+        let frac = n as f64 / total as f64;
+        let bar = "█".repeat((frac * bar_width as f64).round() as usize);
+        ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<24}", kind.to_string()), Style::default().fg(COL_ACCENT)),
+            Span::raw(format!("{:>5} {:>3.0}% ", n, frac * 100.0)),
+            Span::styled(bar, Style::default().fg(COL_FAIL)),
+        ]))
+    }).collect();
+
+    f.render_widget(List::new(items), inner);
 }
 
 fn draw_config(f: &mut Frame, app: &AppState, area: Rect) {
-    let block = Block::default().borders(Borders::ALL).title(" Simulation Parameters ");
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Simulation Parameters ([j/k] select [+/-] edit [a]pply) ");
     let inner = block.inner(area);
     f.render_widget(block, area);
 
     let p = &app.params;
+    let t = &app.tuning;
     
     let kv = |k: &str, v: String| -> ListItem {
         ListItem::new(Line::from(vec![
@@ -414,6 +778,26 @@ fn draw_config(f: &mut Frame, app: &AppState, area: Rect) {
         ]))
     };
 
+    // Live-tunable rows: the running value, then the edited one if it differs
+    let tunable = |row: usize, current: String, edited: Option<String>| -> ListItem {
+        let selected = t.selected == row;
+        let style = if selected {
+            Style::default().fg(COL_HIGHLIGHT).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(COL_ACCENT)
+        };
+        let mut spans = vec![
+            Span::styled(if selected { "> " } else { "  " }, style),
+            Span::styled(format!("{:<13}", TuningPanel::LABELS[row]), style),
+            Span::raw(current),
+        ];
+        if let Some(edited) = edited {
+            spans.push(Span::styled(format!(" -> {}", edited), Style::default().fg(COL_HIGHLIGHT)));
+        }
+        ListItem::new(Line::from(spans))
+    };
+    let changed = |a: f64, b: f64| (a - b).abs() > 1e-9;
+
     let items = vec![
         kv("Atom Count:", p.atom_count.to_string()),
         kv("Box Size:", format!("{:.1} Å", p.box_size)),
@@ -421,11 +805,12 @@ fn draw_config(f: &mut Frame, app: &AppState, area: Rect) {
         ListItem::new(Line::from(" ")),
         kv("Algorithm:", format!("{:?}", p.algorithm)),
         kv("Pop Size:", p.population_size.to_string()),
-        kv("Mutation Rate:", format!("{:.2}", p.mutation_rate)),
         kv("Crossover:", format!("{:.2}", p.crossover_rate)),
         ListItem::new(Line::from(" ")),
-        kv("Temperature:", format!("{:.1} K", p.temperature)),
-        kv("Step Size:", format!("{:.2} Å", p.step_size)),
+        tunable(0, format!("{:.2}", p.mutation_rate), changed(t.mutation_rate, p.mutation_rate).then(|| format!("{:.2}", t.mutation_rate))),
+        tunable(1, format!("{:.1} K", p.temperature), changed(t.temperature, p.temperature).then(|| format!("{:.1} K", t.temperature))),
+        tunable(2, format!("{:.2} Å", p.step_size), changed(t.step_size, p.step_size).then(|| format!("{:.2} Å", t.step_size))),
+        tunable(3, "(GA reseed)".to_string(), None),
     ];
 
     let list = List::new(items).block(Block::default().borders(Borders::NONE));
@@ -6,24 +6,33 @@ use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 
-use anyhow::{anyhow, Context, Result};
-use clap::Parser;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
 use crossbeam_channel::unbounded;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use nalgebra::{Point3, Vector3};
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-use klmc_ultimate::core::domain::{AlgorithmType, Cluster, Params, Species, SystemDefinition};
+use klmc_ultimate::core::domain::{Acceptance, Atom, BhMove, Cluster, CoolingSchedule, GaMode, Lattice, MigrationTopology, Objective, Params, Species, SystemDefinition};
 use klmc_ultimate::core::chemistry::InteractionGrid;
 use klmc_ultimate::engine::evaluator::Evaluator;
 use klmc_ultimate::engine::external::gulp::GulpEvaluator;
+use klmc_ultimate::engine::instrument::InstrumentedEvaluator;
+use klmc_ultimate::engine::properties::PropertyEvaluator;
+use klmc_ultimate::engine::native::forcefield::{NativeEvaluator, RelaxSettings};
+use klmc_ultimate::engine::native::potentials::{Buckingham, PairPotential};
+use klmc_ultimate::engine::remote::{self, RemoteEvaluator};
+use klmc_ultimate::engine::screening::{ScreeningEvaluator, ScreeningPolicy};
 use klmc_ultimate::interface::state::AppState;
 use klmc_ultimate::interface::ui;
-use klmc_ultimate::solvers::bh::BasinHopping;
-use klmc_ultimate::solvers::ga::GeneticAlgorithm;
+use klmc_ultimate::solvers::registry::{SolverContext, SolverRegistry};
+use klmc_ultimate::solvers::ss::Sublattice;
+use klmc_ultimate::solvers::stopping::{StopReason, StoppingCriteria};
+use klmc_ultimate::solvers::{self, Control, SolverEvent};
 
 // --- CLI Definitions ---
 
@@ -38,13 +47,176 @@ struct Args {
     #[arg(short, long, default_value_t = 12)]
     atoms: usize,
 
-    /// Algorithm to run (ga, bh)
+    /// Algorithm to run (ga, bh, sa, pso, pt, mh, random, scan, ss)
     #[arg(short, long, default_value = "ga")]
     algo: String,
 
     /// Initial box size (Angstroms)
     #[arg(short, long, default_value_t = 6.0)]
     box_size: f64,
+
+    /// RNG seed for reproducible runs (0 = random)
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Simulated Annealing cooling schedule (linear, geometric, adaptive)
+    #[arg(long, default_value = "geometric")]
+    cooling: String,
+
+    /// Independent BH walkers (0 = one per thread)
+    #[arg(long, default_value_t = 0)]
+    walkers: usize,
+
+    /// Restart a BH walker stuck in a known funnel after this many steps without improvement (0 = never)
+    #[arg(long, default_value_t = 200)]
+    restart_after: usize,
+
+    /// BH move classes with optional weights, e.g. rattle:0.6,surface:0.3,swap:0.1
+    #[arg(long, value_delimiter = ',', default_value = "rattle")]
+    bh_moves: Vec<String>,
+
+    /// BH acceptance criterion (metropolis, threshold, downhill, tsallis, novelty)
+    #[arg(long, default_value = "metropolis")]
+    acceptance: String,
+
+    /// Tsallis acceptance exponent q (1 = Metropolis)
+    #[arg(long, default_value_t = 1.5)]
+    tsallis_q: f64,
+
+    /// Novelty acceptance: number of recently visited basins that may not be re-entered
+    #[arg(long, default_value_t = 20)]
+    novelty_memory: usize,
+
+    /// Adapt the BH step size to hold this acceptance ratio (off unless given)
+    #[arg(long)]
+    target_acceptance: Option<f64>,
+
+    /// BH moves between step-size adjustments
+    #[arg(long, default_value_t = 50)]
+    adapt_interval: usize,
+
+    /// Also adapt the BH temperature towards the target acceptance ratio
+    #[arg(long)]
+    adapt_temperature: bool,
+
+    /// Number of parallel-tempering replicas
+    #[arg(long, default_value_t = 4)]
+    replicas: usize,
+
+    /// Initial kinetic energy per atom for minima-hopping MD escapes (eV)
+    #[arg(long, default_value_t = 0.2)]
+    md_ekin: f64,
+
+    /// ScanBox: smallest box size (Angstroms)
+    #[arg(long, default_value_t = 4.0)]
+    scan_min: f64,
+
+    /// ScanBox: largest box size (Angstroms)
+    #[arg(long, default_value_t = 10.0)]
+    scan_max: f64,
+
+    /// ScanBox: number of evenly spaced box sizes
+    #[arg(long, default_value_t = 7)]
+    scan_steps: usize,
+
+    /// Stop once the best energy reaches this value (eV)
+    #[arg(long, allow_negative_numbers = true)]
+    target_energy: Option<f64>,
+
+    /// Stop after this many seconds of searching (0 = unlimited)
+    #[arg(long, default_value_t = 0.0)]
+    time_limit: f64,
+
+    /// Stop after this many energy evaluations (0 = unlimited)
+    #[arg(long, default_value_t = 0)]
+    max_evals: usize,
+
+    /// Stop after this many steps (generations for the GA) without a new best (0 = never)
+    #[arg(long, default_value_t = 0)]
+    stall: usize,
+
+    /// Stop once the best structure has been found this many times (0 = never)
+    #[arg(long, default_value_t = 0)]
+    repeat_hits: usize,
+
+    /// Solid solution: fraction of Mg sites substituted by Ca
+    #[arg(long, default_value_t = 0.25)]
+    ca_fraction: f64,
+
+    /// Solid solution: rock-salt conventional cells along each axis of the host supercell
+    #[arg(long, default_value_t = 2)]
+    supercell: usize,
+
+    /// Use the asynchronous steady-state GA instead of generational batches
+    #[arg(long)]
+    steady_state: bool,
+
+    /// Learn mutation operator probabilities instead of always using the classic recipe
+    #[arg(long)]
+    adaptive_operators: bool,
+
+    /// Multi-objective GA: comma-separated objectives (energy, dipole, symmetry, or any property
+    /// the evaluator reports, e.g. gap); prefix with max: to maximise. Empty = energy only
+    #[arg(long, value_delimiter = ',')]
+    objectives: Vec<String>,
+
+    /// Island-model GA: number of subpopulations (0 or 1 = single population; takes precedence over --steady-state)
+    #[arg(long, default_value_t = 0)]
+    islands: usize,
+
+    /// Island-model GA: migration topology (ring, full)
+    #[arg(long, default_value = "ring")]
+    migration: String,
+
+    /// Island-model GA: generations between migrations
+    #[arg(long, default_value_t = 10)]
+    migration_interval: usize,
+
+    /// Island-model GA: ratio of the last island's mutation rate to the first's
+    #[arg(long, default_value_t = 1.0)]
+    mutation_spread: f64,
+
+    /// Memetic GA: generations between local BH refinements of the elites (0 = off)
+    #[arg(long, default_value_t = 0)]
+    memetic: usize,
+
+    /// Memetic GA: best unique members refined per phase
+    #[arg(long, default_value_t = 3)]
+    memetic_elites: usize,
+
+    /// Memetic GA: BH moves per refined member
+    #[arg(long, default_value_t = 10)]
+    memetic_steps: usize,
+
+    /// Pre-screen with a native Buckingham model; only candidates within this
+    /// window (eV) of the best pre-relaxed energy are sent to GULP
+    #[arg(long)]
+    screen_window: Option<f64>,
+
+    /// Evaluate on remote workers instead of local GULP (comma-separated host:port list)
+    #[arg(long, value_delimiter = ',')]
+    remote: Vec<String>,
+
+    /// Maximum concurrent evaluations sent to each remote worker
+    #[arg(long, default_value_t = 4)]
+    remote_jobs: usize,
+
+    #[command(subcommand)]
+    command: Option<Mode>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Mode {
+    /// Serve GULP evaluations to remote KLMC clients over TCP
+    Worker {
+        /// Address to listen on
+        #[arg(long, default_value = "0.0.0.0:7878")]
+        bind: String,
+
+        /// Maximum concurrent GULP processes on this machine
+        #[arg(long, default_value_t = 4)]
+        max_jobs: usize,
+    },
 }
 
 // --- Terminal Guard (RAII) ---
@@ -90,7 +262,7 @@ fn setup_panic_hook() {
     }));
 }
 
-fn create_default_system(args: &Args) -> SystemDefinition {
+fn create_default_system(args: &Args) -> Result<SystemDefinition> {
     // Define MgO system
     // Index 0 = Mg
     let mg = Species {
@@ -114,10 +286,48 @@ fn create_default_system(args: &Args) -> SystemDefinition {
         color_rgb: (255, 0, 0), // Red
     };
 
-    let algo = match args.algo.to_lowercase().as_str() {
-        "bh" => AlgorithmType::BasinHopping,
-        "scan" => AlgorithmType::ScanBox,
-        _ => AlgorithmType::GeneticAlgorithm,
+    let algo = SolverRegistry::builtin().lookup(&args.algo)?.algorithm;
+
+    let migration_topology = match args.migration.to_lowercase().as_str() {
+        "ring" => MigrationTopology::Ring,
+        "full" | "fully-connected" => MigrationTopology::FullyConnected,
+        other => bail!("Unknown migration topology '{}' (expected ring or full)", other),
+    };
+
+    let ga_mode = if args.islands > 1 {
+        GaMode::Island
+    } else if args.steady_state {
+        GaMode::SteadyState
+    } else {
+        GaMode::Generational
+    };
+
+    let mut bh_moves = Vec::with_capacity(args.bh_moves.len());
+    for spec in &args.bh_moves {
+        let Some(mv) = BhMove::parse(spec) else {
+            let names: Vec<&str> = BhMove::ALL.iter().map(|m| m.name()).collect();
+            bail!("Invalid BH move '{}' (expected one of: {}, optionally as name:weight)", spec, names.join(", "));
+        };
+        bh_moves.push(mv);
+    }
+    if bh_moves.is_empty() {
+        bh_moves.push((BhMove::Rattle, 1.0));
+    }
+
+    let acceptance = match args.acceptance.to_lowercase().as_str() {
+        "metropolis" => Acceptance::Metropolis,
+        "threshold" => Acceptance::Threshold,
+        "downhill" => Acceptance::Downhill,
+        "tsallis" => Acceptance::Tsallis,
+        "novelty" => Acceptance::Novelty,
+        other => bail!("Unknown acceptance criterion '{}' (expected metropolis, threshold, downhill, tsallis or novelty)", other),
+    };
+
+    let cooling = match args.cooling.to_lowercase().as_str() {
+        "linear" => CoolingSchedule::Linear,
+        "geometric" => CoolingSchedule::Geometric,
+        "adaptive" => CoolingSchedule::Adaptive,
+        other => bail!("Unknown cooling schedule '{}' (expected linear, geometric or adaptive)", other),
     };
 
     // Stoichiometry Setup: 50/50 split for MgO
@@ -127,6 +337,7 @@ fn create_default_system(args: &Args) -> SystemDefinition {
 
     let params = Params {
         algorithm: algo,
+        seed: args.seed,
         threads: args.threads,
         atom_count: args.atoms,
         atom_counts, // Explicit stoichiometry
@@ -136,16 +347,134 @@ fn create_default_system(args: &Args) -> SystemDefinition {
         mutation_rate: 0.2,
         crossover_rate: 0.6,
         elitism_count: 2,
+        ga_mode,
+        adaptive_operators: args.adaptive_operators,
+        objectives: args.objectives.iter().map(|o| Objective::parse(o)).collect(),
+        islands: args.islands.max(1),
+        migration_interval: args.migration_interval,
+        migration_size: 2,
+        migration_topology,
+        mutation_spread: args.mutation_spread,
+        memetic_interval: args.memetic,
+        memetic_elites: args.memetic_elites,
+        memetic_steps: args.memetic_steps,
         temperature: 300.0,
         step_size: 0.1,
         max_steps: 1000,
-        ..Default::default()
+        walkers: if args.walkers == 0 { args.threads } else { args.walkers },
+        restart_after: args.restart_after,
+        bh_moves,
+        acceptance,
+        tsallis_q: args.tsallis_q,
+        novelty_memory: args.novelty_memory,
+        adaptive_step: args.target_acceptance.is_some(),
+        target_acceptance: args.target_acceptance.unwrap_or(0.5),
+        adapt_interval: args.adapt_interval,
+        adapt_temperature: args.adapt_temperature,
+        cooling,
+        final_temperature: 1.0,
+        reheat_after: 200,
+        reheat_fraction: 0.5,
+        inertia: 0.7,
+        cognitive_weight: 1.5,
+        social_weight: 1.5,
+        max_velocity: 0.5,
+        replicas: args.replicas,
+        max_temperature: 3000.0,
+        swap_interval: 10,
+        md_kinetic_energy: args.md_ekin,
+        md_timestep: 0.01,
+        md_minima: 3,
+        hop_ediff: 0.1,
+        scan_box_min: args.scan_min,
+        scan_box_max: args.scan_max,
+        scan_steps: args.scan_steps,
+        target_energy: args.target_energy,
+        time_limit: args.time_limit,
+        max_evaluations: args.max_evals,
+        stall_steps: args.stall,
+        repeat_hits: args.repeat_hits,
+    };
+
+    // Index 2 = Ca (solid-solution dopant on the Mg sublattice)
+    let ca = Species {
+        symbol: "Ca".to_string(),
+        atomic_number: 20,
+        mass: 40.078,
+        charge: 2.0,
+        radius_covalent: 1.76,
+        radius_ionic: 1.00,
+        color_rgb: (0, 255, 0), // Green
     };
 
-    SystemDefinition {
-        species: vec![mg, o],
+    Ok(SystemDefinition {
+        species: vec![mg, o, ca],
         params,
+    })
+}
+
+/// Buckingham potential block passed to GULP.
+const GULP_POTENTIALS: &str = r#"
+buckingham
+Mg core O core 1280.1 0.29969 0.0 0.0 10.0
+O core O core 22764.0 0.149 27.88 0.0 10.0
+Ca core O core 1228.9 0.3372 0.0 0.0 10.0
+spring
+Mg 0.0
+O 0.0
+Ca 0.0
+"#;
+
+fn create_gulp_evaluator(system: &SystemDefinition) -> Arc<dyn Evaluator> {
+    let species_map = system.species.clone();
+    Arc::new(GulpEvaluator::new("gulp", GULP_POTENTIALS.trim(), species_map))
+}
+
+/// Native rigid-ion copy of the GULP potential.
+fn create_native_potential(system: &SystemDefinition) -> Arc<dyn PairPotential> {
+    let charges = system.species.iter().map(|s| s.charge).collect();
+    Arc::new(
+        Buckingham::new(charges, 10.0)
+            .with_pair(0, 1, 1280.1, 0.29969, 0.0)
+            .with_pair(1, 1, 22764.0, 0.149, 27.88)
+            .with_pair(2, 1, 1228.9, 0.3372, 0.0),
+    )
+}
+
+/// Rock-salt MgO supercell (`cells`³ conventional cells) with the Mg sites open to Ca.
+fn create_solid_solution_host(cells: usize, ca_fraction: f64) -> (Cluster, Sublattice) {
+    const A_MGO: f64 = 4.212;
+    const CATIONS: [[f64; 3]; 4] = [[0.0, 0.0, 0.0], [0.0, 0.5, 0.5], [0.5, 0.0, 0.5], [0.5, 0.5, 0.0]];
+    const ANIONS: [[f64; 3]; 4] = [[0.5, 0.0, 0.0], [0.0, 0.5, 0.0], [0.0, 0.0, 0.5], [0.5, 0.5, 0.5]];
+
+    let n = cells.max(1);
+    let edge = A_MGO * n as f64;
+    let mut host = Cluster::new("RockSalt_Host");
+    host.lattice = Lattice::new(Vector3::x() * edge, Vector3::y() * edge, Vector3::z() * edge);
+
+    let mut sites = Vec::new();
+    for (x, y, z) in (0..n).flat_map(|x| (0..n).flat_map(move |y| (0..n).map(move |z| (x, y, z)))) {
+        for (element_id, basis) in [(0, &CATIONS), (1, &ANIONS)] {
+            for b in basis {
+                if element_id == 0 { sites.push(host.atoms.len()); }
+                host.atoms.push(Atom {
+                    element_id,
+                    position: Point3::new(x as f64 + b[0], y as f64 + b[1], z as f64 + b[2]) * A_MGO,
+                    velocity: Vector3::zeros(),
+                    force: Vector3::zeros(),
+                    is_fixed: false,
+                });
+            }
+        }
     }
+
+    let fraction = ca_fraction.clamp(0.0, 1.0);
+    (host, Sublattice { sites, fractions: vec![(0, 1.0 - fraction), (2, fraction)] })
+}
+
+/// Native evaluator used for pre-screening and annealing moves.
+fn create_screening_model(system: &SystemDefinition, settings: RelaxSettings) -> Arc<dyn Evaluator> {
+    Arc::new(NativeEvaluator::new(create_native_potential(system)).with_settings(settings))
 }
 
 fn check_dependencies() -> Result<()> {
@@ -160,6 +489,15 @@ fn check_dependencies() -> Result<()> {
     }
 }
 
+/// Headless worker mode: serves GULP evaluations until killed.
+fn run_worker(system: &SystemDefinition, bind: &str, max_jobs: usize) -> Result<()> {
+    let listener = std::net::TcpListener::bind(bind)
+        .with_context(|| format!("Failed to bind worker to {}", bind))?;
+    let evaluator = create_gulp_evaluator(system);
+    println!("KLMC worker listening on {} ({}, {} slots)", bind, evaluator.name(), max_jobs);
+    remote::serve(listener, evaluator, max_jobs)
+}
+
 // --- Main ---
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -167,31 +505,55 @@ fn main() -> Result<(), Box<dyn Error>> {
     setup_panic_hook();
     let args = Args::parse();
 
-    // 2. Pre-flight Checks
-    if let Err(e) = check_dependencies() {
-        eprintln!("{}", e); 
-        std::process::exit(1);
+    // 2. Pre-flight Checks (GULP is only needed where evaluations run locally)
+    if args.remote.is_empty() {
+        if let Err(e) = check_dependencies() {
+            eprintln!("{}", e); 
+            std::process::exit(1);
+        }
     }
 
     // 3. Initialize System
-    let system = create_default_system(&args);
+    let system = create_default_system(&args)?;
+
+    if let Some(Mode::Worker { bind, max_jobs }) = &args.command {
+        run_worker(&system, bind, *max_jobs)?;
+        return Ok(());
+    }
     
     // 4. Initialize Physics Components
     let grid = Arc::new(InteractionGrid::new(&system.species, 0.75));
     
-    // Evaluator: GULP wrapper
-    // We define the Buckingham potential string here
-    let species_map = system.species.clone();
-    let potentials = r#"
-buckingham
-Mg core O core 1280.1 0.29969 0.0 0.0 10.0
-O core O core 22764.0 0.149 27.88 0.0 10.0
-spring
-Mg 0.0
-O 0.0
-    "#.trim().to_string();
+    // Evaluator: local GULP wrapper, or a pool of remote workers
+    let base: Arc<dyn Evaluator> = if args.remote.is_empty() {
+        create_gulp_evaluator(&system)
+    } else {
+        let workers = args.remote.iter().map(|a| (a.clone(), args.remote_jobs)).collect();
+        let client = RemoteEvaluator::new(workers);
+        for (addr, status) in client.probe() {
+            match status {
+                Ok((engine, slots)) => println!("Worker {}: {} ({} slots)", addr, engine, slots),
+                Err(e) => eprintln!("Worker {} unreachable: {:#}", addr, e),
+            }
+        }
+        Arc::new(client)
+    };
 
-    let evaluator: Arc<dyn Evaluator> = Arc::new(GulpEvaluator::new("gulp", &potentials, species_map));
+    let evaluator: Arc<dyn Evaluator> = match args.screen_window {
+        Some(window) => Arc::new(ScreeningEvaluator::new(
+            create_screening_model(&system, RelaxSettings::default()),
+            base,
+            ScreeningPolicy::EnergyWindow(window),
+        )),
+        None => base,
+    };
+    let evaluator: Arc<dyn Evaluator> = if system.params.objectives.is_empty() {
+        evaluator
+    } else {
+        let charges = system.species.iter().map(|s| s.charge).collect();
+        Arc::new(PropertyEvaluator::new(evaluator, charges))
+    };
+    let evaluator: Arc<dyn Evaluator> = Arc::new(InstrumentedEvaluator::new(evaluator));
 
     // 5. Setup TUI & App State
     let mut tui = TuiContext::new().context("Failed to initialize TUI")?;
@@ -200,39 +562,40 @@ O 0.0
     // 6. Spawn Solver Thread
     let (tx, rx) = unbounded();
     app.set_channel(rx);
+    let (cmd_tx, cmd_rx) = unbounded();
+    app.set_control(cmd_tx);
+
+    let threads = system.params.threads;
+    let mut ctx = SolverContext::new(evaluator.clone(), grid.clone(), system.params.clone());
+
+    // SA moves use a lightly relaxed native model; the final best is refined with the main evaluator
+    let light = RelaxSettings { max_iterations: 25, ..RelaxSettings::default() };
+    ctx.screening = Some(Arc::new(InstrumentedEvaluator::new(create_screening_model(&system, light))));
+    // Minima hopping integrates its MD escapes on the native potential
+    ctx.potential = Some(create_native_potential(&system));
+    ctx.host = Some(create_solid_solution_host(args.supercell, args.ca_fraction));
+
+    // Evaluation budget and repeat hits are counted on the main model only; screening calls are free
+    let mut stopping = StoppingCriteria::new(&system.params);
+    if stopping.is_active() {
+        ctx.evaluator = stopping.monitor(ctx.evaluator);
+    }
 
-    let params_clone = system.params.clone();
-    let grid_clone = grid.clone();
-    let eval_clone = evaluator.clone();
-
-    thread::Builder::new()
+    let worker = thread::Builder::new()
         .name("Solver-Worker".to_string())
         .spawn(move || {
             // Initialize Rayon global thread pool for parallel evaluations
             let _ = rayon::ThreadPoolBuilder::new()
-                .num_threads(params_clone.threads)
+                .num_threads(threads)
                 .build_global();
 
-            match params_clone.algorithm {
-                AlgorithmType::GeneticAlgorithm => {
-                    let solver = GeneticAlgorithm::new(eval_clone, grid_clone, params_clone);
-                    solver.solve(tx);
-                }
-                AlgorithmType::BasinHopping => {
-                    let mut rng = rand::thread_rng();
-                    // Generate a valid starting cluster with correct stoichiometry
-                    let start_cluster = Cluster::new_random(
-                        &params_clone.atom_counts, // Pass ref to Vec<usize>
-                        params_clone.box_size, 
-                        &grid_clone, 
-                        &mut rng
-                    ).unwrap_or_else(|| Cluster::new("Fallback_Empty"));
-
-                    let solver = BasinHopping::new(eval_clone, grid_clone, params_clone);
-                    solver.solve(start_cluster, tx);
+            match SolverRegistry::builtin().build(ctx) {
+                Ok(mut solver) => {
+                    solvers::run_controlled(solver.as_mut(), tx, &Control::new(cmd_rx), &mut stopping);
                 }
-                _ => {
-                    // Stub for other algorithms
+                Err(e) => {
+                    let _ = tx.send(SolverEvent::Log(format!("{:#}", e)));
+                    let _ = tx.send(SolverEvent::Finished(StopReason::Failed));
                 }
             }
         })?;
@@ -266,5 +629,13 @@ O 0.0
         }
     }
 
+    // 8. Shutdown: let the solver finish its current evaluations instead of abandoning them
+    app.stop();
+    drop(tui);
+    if !worker.is_finished() {
+        println!("Waiting for the solver to stop...");
+    }
+    let _ = worker.join();
+
     Ok(())
 }
\ No newline at end of file
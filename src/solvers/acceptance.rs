@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use rand::{Rng, RngCore};
+
+use crate::analysis::topology;
+use crate::core::domain::{Acceptance, Cluster, Params};
+use crate::solvers::bh::KB_EV;
+
+/// Decides whether a basin-hopping walker moves from `current` to the relaxed `trial`.
+/// Implementations may keep state (e.g. visited basins), so each walker owns its own.
+pub trait AcceptanceCriterion: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// `temperature` (K) is the walker's current temperature.
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, temperature: f64, rng: &mut dyn RngCore) -> bool;
+}
+
+/// The criterion selected by `params.acceptance`.
+pub fn build(params: &Params) -> Box<dyn AcceptanceCriterion> {
+    match params.acceptance {
+        Acceptance::Metropolis => Box::new(Metropolis),
+        Acceptance::Threshold => Box::new(Threshold),
+        Acceptance::Downhill => Box::new(Downhill),
+        Acceptance::Tsallis => Box::new(Tsallis { q: params.tsallis_q }),
+        Acceptance::Novelty => Box::new(Novelty::new(params.novelty_memory)),
+    }
+}
+
+/// Metropolis criterion: downhill always, uphill with probability `exp(-ΔE / kT)`.
+/// A temperature of (near) zero is a pure quench.
+pub fn metropolis(delta: f64, temperature: f64, rng: &mut (impl Rng + ?Sized)) -> bool {
+    if delta < 0.0 {
+        true
+    } else if temperature <= 1e-9 {
+        false // Quench only
+    } else {
+        let prob = (-delta / (KB_EV * temperature)).exp();
+        rng.gen::<f64>() < prob
+    }
+}
+
+fn delta(current: &Cluster, trial: &Cluster) -> f64 {
+    trial.energy.unwrap_or(f64::MAX) - current.energy.unwrap_or(f64::MAX)
+}
+
+/// Boltzmann acceptance (see `metropolis`).
+pub struct Metropolis;
+
+impl AcceptanceCriterion for Metropolis {
+    fn name(&self) -> &str { "Metropolis" }
+
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, temperature: f64, rng: &mut dyn RngCore) -> bool {
+        metropolis(delta(current, trial), temperature, rng)
+    }
+}
+
+/// Threshold accepting (Dueck & Scheuer, J. Comput. Phys. 90, 161): any move less than
+/// `kT` uphill is accepted, deterministically.
+pub struct Threshold;
+
+impl AcceptanceCriterion for Threshold {
+    fn name(&self) -> &str { "Threshold" }
+
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, temperature: f64, _rng: &mut dyn RngCore) -> bool {
+        delta(current, trial) < KB_EV * temperature.max(0.0)
+    }
+}
+
+/// Pure descent: only moves that lower the energy are accepted.
+pub struct Downhill;
+
+impl AcceptanceCriterion for Downhill {
+    fn name(&self) -> &str { "Downhill" }
+
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, _temperature: f64, _rng: &mut dyn RngCore) -> bool {
+        delta(current, trial) < 0.0
+    }
+}
+
+/// Generalised (Tsallis) acceptance: uphill with probability `[1 - (1 - q) ΔE / kT]^(1 / (1 - q))`,
+/// zero where the bracket is not positive. `q > 1` gives a heavier tail than Metropolis,
+/// which is recovered as `q → 1`.
+pub struct Tsallis {
+    pub q: f64,
+}
+
+impl AcceptanceCriterion for Tsallis {
+    fn name(&self) -> &str { "Tsallis" }
+
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, temperature: f64, rng: &mut dyn RngCore) -> bool {
+        let d = delta(current, trial);
+        if (self.q - 1.0).abs() < 1e-9 || d < 0.0 || temperature <= 1e-9 {
+            return metropolis(d, temperature, rng);
+        }
+        let base = 1.0 - (1.0 - self.q) * d / (KB_EV * temperature);
+        let prob = if base > 0.0 { base.powf(1.0 / (1.0 - self.q)) } else { 0.0 };
+        rng.gen::<f64>() < prob
+    }
+}
+
+/// Metropolis, except that moves back into one of the last `memory` basins the walker
+/// occupied (identified by `hash_key`) are always rejected.
+pub struct Novelty {
+    memory: usize,
+    visited: VecDeque<String>,
+}
+
+impl Novelty {
+    pub fn new(memory: usize) -> Self {
+        Self { memory: memory.max(1), visited: VecDeque::new() }
+    }
+
+    fn remember(&mut self, key: String) {
+        if self.visited.back() == Some(&key) { return; }
+        self.visited.retain(|k| *k != key);
+        self.visited.push_back(key);
+        while self.visited.len() > self.memory {
+            self.visited.pop_front();
+        }
+    }
+}
+
+fn basin(cluster: &Cluster) -> String {
+    cluster.hash_key.clone().unwrap_or_else(|| topology::generate_hash_key(cluster, 1.5))
+}
+
+impl AcceptanceCriterion for Novelty {
+    fn name(&self) -> &str { "Novelty" }
+
+    fn accept(&mut self, current: &Cluster, trial: &Cluster, temperature: f64, rng: &mut dyn RngCore) -> bool {
+        self.remember(basin(current));
+        let key = basin(trial);
+        if self.visited.contains(&key) {
+            return false;
+        }
+        let accepted = metropolis(delta(current, trial), temperature, rng);
+        if accepted {
+            self.remember(key);
+        }
+        accepted
+    }
+}
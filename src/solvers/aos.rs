@@ -0,0 +1,133 @@
+use rand::Rng;
+
+use crate::engine::operators::MutationOp;
+use crate::solvers::OperatorStats;
+
+/// Reward for a child that set a new best energy.
+pub const REWARD_BEST: f64 = 1.0;
+/// Reward for a child that entered the population (beat its worst member).
+pub const REWARD_ENTERED: f64 = 0.5;
+
+/// Floor on every selection probability, so no operator is starved of trials.
+const P_MIN: f64 = 0.05;
+/// Smoothing rate for the quality estimates.
+const ALPHA: f64 = 0.3;
+/// Rate at which probabilities pursue the current best operator.
+const BETA: f64 = 0.3;
+
+/// Adaptive operator selection by adaptive pursuit (Thierens, GECCO 2005).
+///
+/// Children carry their operator's name in `Cluster::origin`. Every evaluated child counts as
+/// one use of its operator and earns a reward; at the end of each generation the quality of
+/// every operator used is moved towards its mean reward, and the probability of the
+/// highest-quality operator is pushed towards `1 - (n - 1) * P_MIN` while the others
+/// decay towards `P_MIN`.
+#[derive(Debug, Clone)]
+pub struct OperatorPool {
+    ops: Vec<MutationOp>,
+    probability: Vec<f64>,
+    quality: Vec<f64>,
+    uses: Vec<usize>,
+    successes: Vec<usize>,
+    window_uses: Vec<usize>,
+    window_reward: Vec<f64>,
+}
+
+impl OperatorPool {
+    pub fn new(ops: &[MutationOp]) -> Self {
+        let n = ops.len().max(1);
+        Self {
+            ops: ops.to_vec(),
+            probability: vec![1.0 / n as f64; ops.len()],
+            quality: vec![REWARD_ENTERED; ops.len()],
+            uses: vec![0; ops.len()],
+            successes: vec![0; ops.len()],
+            window_uses: vec![0; ops.len()],
+            window_reward: vec![0.0; ops.len()],
+        }
+    }
+
+    /// Roulette-wheel draw over the current probabilities.
+    pub fn select(&self, rng: &mut impl Rng) -> MutationOp {
+        let mut r = rng.gen::<f64>();
+        for (op, p) in self.ops.iter().zip(&self.probability) {
+            if r < *p {
+                return *op;
+            }
+            r -= p;
+        }
+        self.ops.last().copied().unwrap_or(MutationOp::Classic)
+    }
+
+    /// Counts one evaluated child bred with the operator named `origin` and adds its reward.
+    /// Children from elsewhere (crossover only, refills, reseeds) are ignored.
+    pub fn credit(&mut self, origin: &str, reward: f64) {
+        let Some(i) = self.ops.iter().position(|op| op.name() == origin) else { return; };
+        self.uses[i] += 1;
+        self.window_uses[i] += 1;
+        self.window_reward[i] += reward;
+        if reward > 0.0 {
+            self.successes[i] += 1;
+        }
+    }
+
+    /// Folds the rewards collected since the last call into the qualities and probabilities.
+    pub fn update(&mut self) {
+        let n = self.ops.len();
+        if n == 0 || self.window_uses.iter().all(|&u| u == 0) {
+            return;
+        }
+
+        for i in 0..n {
+            if self.window_uses[i] > 0 {
+                let mean = self.window_reward[i] / self.window_uses[i] as f64;
+                self.quality[i] += ALPHA * (mean - self.quality[i]);
+            }
+        }
+        self.window_uses.iter_mut().for_each(|u| *u = 0);
+        self.window_reward.iter_mut().for_each(|r| *r = 0.0);
+
+        let p_min = P_MIN.min(1.0 / n as f64);
+        let p_max = 1.0 - (n - 1) as f64 * p_min;
+        let best = (0..n)
+            .max_by(|&a, &b| self.quality[a].partial_cmp(&self.quality[b]).unwrap_or(std::cmp::Ordering::Equal))
+            .unwrap_or(0);
+        for (i, p) in self.probability.iter_mut().enumerate() {
+            let target = if i == best { p_max } else { p_min };
+            *p += BETA * (target - *p);
+        }
+    }
+
+    pub fn stats(&self) -> Vec<OperatorStats> {
+        (0..self.ops.len())
+            .map(|i| OperatorStats {
+                name: self.ops[i].name().to_string(),
+                probability: self.probability[i],
+                quality: self.quality[i],
+                uses: self.uses[i],
+                successes: self.successes[i],
+            })
+            .collect()
+    }
+}
+
+/// Combines the pools of several islands: probabilities and qualities are averaged,
+/// counts summed. Pools are assumed to share the same operator list.
+pub fn merged_stats(pools: &[&OperatorPool]) -> Vec<OperatorStats> {
+    let Some(first) = pools.first() else { return Vec::new(); };
+    let k = pools.len() as f64;
+    let mut merged = first.stats();
+    for pool in &pools[1..] {
+        for (m, s) in merged.iter_mut().zip(pool.stats()) {
+            m.probability += s.probability;
+            m.quality += s.quality;
+            m.uses += s.uses;
+            m.successes += s.successes;
+        }
+    }
+    for m in &mut merged {
+        m.probability /= k;
+        m.quality /= k;
+    }
+    merged
+}
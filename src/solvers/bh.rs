@@ -1,19 +1,143 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
+use anyhow::{anyhow, bail, Result};
 use crossbeam_channel::Sender;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
 
-use crate::core::domain::{Cluster, Params, ClusterStatus};
-use crate::engine::evaluator::Evaluator;
+use crate::core::domain::{BhMove, Cluster, Params, EvalFailure};
+use crate::engine::evaluator::{Evaluator, classify_failure};
 use crate::engine::operators::Mutator;
 use crate::core::spatial;
 use crate::core::chemistry::InteractionGrid;
-use crate::solvers::{SolverEvent, GenStats};
+use crate::analysis::topology;
+use crate::solvers::acceptance::{self, AcceptanceCriterion};
+use crate::solvers::hof::HallOfFame;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, Tuning, GenStats, FailureTally, report_evaluator, seeded_rng};
+
+pub(crate) const KB_EV: f64 = 8.617333262e-5; // Boltzmann constant (eV/K)
+/// Multi-walker mode: steps each walker takes between synchronisation points.
+const WALKER_BLOCK: usize = 10;
+/// Multi-walker mode: size of the shared Hall of Fame.
+const HOF_CAPACITY: usize = 50;
+/// Attempts at building a relaxed random start before giving up.
+const START_ATTEMPTS: usize = 10;
+/// Multiplicative step-size (and temperature) change per adaptation window.
+const ADAPT_FACTOR: f64 = 0.9;
+/// Smallest step size the controller will shrink to (Å).
+const MIN_STEP: f64 = 0.01;
+
+/// Result of a single perturb-relax-accept move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Move {
+    Accepted,
+    Rejected,
+    Failed(EvalFailure),
+}
+
+/// Holds a target acceptance ratio by rescaling the step size every `adapt_interval` moves:
+/// larger when too many moves are accepted, smaller when too few. With `adapt_temperature`
+/// the temperature moves the other way. Without `adaptive_step` the values stay fixed and
+/// only the acceptance ratio is tracked.
+#[derive(Debug, Clone)]
+pub struct StepController {
+    pub step_size: f64,
+    pub temperature: f64,
+    enabled: bool,
+    adapt_temperature: bool,
+    target: f64,
+    interval: usize,
+    max_step: f64,
+    moves: usize,
+    accepted: usize,
+    acceptance: Option<f64>,
+}
+
+impl StepController {
+    pub fn new(params: &Params) -> Self {
+        Self {
+            step_size: params.step_size,
+            temperature: params.temperature,
+            enabled: params.adaptive_step && params.adapt_interval > 0,
+            adapt_temperature: params.adapt_temperature,
+            target: params.target_acceptance.clamp(0.0, 1.0),
+            interval: params.adapt_interval.max(1),
+            max_step: (params.box_size * 0.5).max(params.step_size),
+            moves: 0,
+            accepted: 0,
+            acceptance: None,
+        }
+    }
+
+    /// Counts one move (failed moves count as rejected) and adapts at the end of each window.
+    pub fn record(&mut self, accepted: bool) {
+        self.moves += 1;
+        if accepted {
+            self.accepted += 1;
+        }
+        if self.moves < self.interval {
+            return;
+        }
+
+        let acceptance = self.accepted as f64 / self.moves as f64;
+        self.acceptance = Some(acceptance);
+        self.moves = 0;
+        self.accepted = 0;
+        if !self.enabled {
+            return;
+        }
+
+        let too_easy = acceptance > self.target;
+        let scale = if too_easy { 1.0 / ADAPT_FACTOR } else { ADAPT_FACTOR };
+        self.step_size = (self.step_size * scale).clamp(MIN_STEP, self.max_step);
+        if self.adapt_temperature {
+            self.temperature /= scale;
+        }
+    }
+
+    /// Acceptance ratio of the last completed window (of the moves so far before the first).
+    pub fn acceptance(&self) -> f64 {
+        match self.acceptance {
+            Some(a) => a,
+            None if self.moves > 0 => self.accepted as f64 / self.moves as f64,
+            None => 0.0,
+        }
+    }
+}
+
+/// One independent walker in multi-walker mode.
+struct Walker {
+    id: usize,
+    current: Cluster,
+    best: Cluster,
+    rng: ChaCha8Rng,
+    control: StepController,
+    criterion: Box<dyn AcceptanceCriterion>,
+    stagnation: usize,
+    /// Moves attempted; walkers can run ahead of `Run::done` when a stop cuts a block short.
+    steps: usize,
+    accepted: usize,
+    restarts: usize,
+    failures: FailureTally,
+}
 
 pub struct BasinHopping {
     evaluator: Arc<dyn Evaluator>,
     grid: Arc<InteractionGrid>,
     params: Params,
+    start: Option<Cluster>,
+    run: Option<Run>,
+}
+
+/// Walker state between steps. The single-walker mode runs one walker and reports every move.
+struct Run {
+    walkers: Vec<Walker>,
+    /// Multi-walker mode: minima found by all walkers.
+    hof: HallOfFame,
+    done: usize,
+    start_time: Instant,
 }
 
 impl BasinHopping {
@@ -26,148 +150,315 @@ impl BasinHopping {
             evaluator,
             grid,
             params,
+            start: None,
+            run: None,
         }
     }
 
+    /// Starts the (first) walker from `cluster` instead of a random structure.
+    pub fn with_start(mut self, cluster: Cluster) -> Self {
+        self.start = Some(cluster);
+        self
+    }
+
     /// Runs the Basin Hopping loop (Monte Carlo Minimization).
-    /// Tracks a single "Walker" cluster across the energy landscape.
-    pub fn solve(&self, mut current: Cluster, tx: Sender<SolverEvent>) {
-        let mut rng = rand::thread_rng();
-        let kb_ev = 8.617333262e-5; // Boltzmann constant
-        
-        // Defensive: Validate inputs
-        if self.params.max_steps == 0 {
-            let _ = tx.send(SolverEvent::Log("Max steps set to 0. Exiting.".to_string()));
-            let _ = tx.send(SolverEvent::Finished);
-            return;
-        }
+    /// Tracks a single "Walker" cluster across the energy landscape,
+    /// or `params.walkers` independent walkers in parallel (see `walker_block`).
+    pub fn solve(self, start: Cluster, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self.with_start(start), tx);
+    }
+
+    /// Single-walker start-up: relaxes the starting structure.
+    fn start_walker(&mut self, tx: &Sender<SolverEvent>) -> Result<Vec<Walker>> {
+        let mut rng = seeded_rng(self.params.seed);
+
+        let mut current = match self.start.take() {
+            Some(c) => c,
+            None => Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, &mut rng)
+                .ok_or_else(|| anyhow!("CRITICAL: Failed to generate a starting structure."))?,
+        };
 
         // 1. Initial Relaxation
         if current.energy.is_none() {
             let _ = tx.send(SolverEvent::Log("Relaxing initial structure...".to_string()));
-            match self.evaluator.evaluate(&current) {
-                Ok(res) => {
-                    current.energy = Some(res.energy);
-                    
-                    // Safe Geometry Update
-                    if let Some(geom) = res.relaxed_cluster {
-                        if geom.atoms.len() == current.atoms.len() {
-                            for (orig, new) in current.atoms.iter_mut().zip(geom.atoms.iter()) {
-                                orig.position = new.position;
-                            }
-                            if geom.lattice.is_some() { current.lattice = geom.lattice; }
-                            spatial::wrap_or_center(&mut current);
-                        }
-                    }
-                    current.status = ClusterStatus::Evaluated;
-                },
-                Err(e) => {
-                    let _ = tx.send(SolverEvent::Log(format!("Initial relaxation failed: {}", e)));
-                    let _ = tx.send(SolverEvent::Finished);
-                    return;
+            self.relax(&mut current).map_err(|e| anyhow!("Initial relaxation failed: {}", e))?;
+        }
+
+        if current.energy.is_some() {
+            let _ = tx.send(SolverEvent::NewBest(current.clone()));
+        }
+
+        let criterion = acceptance::build(&self.params);
+        let _ = tx.send(SolverEvent::Log(format!("BH acceptance: {}", criterion.name())));
+
+        Ok(vec![Walker {
+            id: 0,
+            best: current.clone(),
+            current,
+            rng,
+            control: StepController::new(&self.params),
+            criterion,
+            stagnation: 0,
+            steps: 0,
+            accepted: 0,
+            restarts: 0,
+            failures: FailureTally::default(),
+        }])
+    }
+
+    /// Multi-walker start-up: `params.walkers` independent walkers, the first from the
+    /// starting structure (if any), the rest from fresh random clusters.
+    fn start_walkers(&mut self, tx: &Sender<SolverEvent>) -> Result<Vec<Walker>> {
+        let mut rng = seeded_rng(self.params.seed);
+        let start = self.start.take();
+
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Starting {} BH walkers ({} acceptance)...",
+            self.params.walkers, acceptance::build(&self.params).name()
+        )));
+        let seeds: Vec<u64> = (0..self.params.walkers).map(|_| rng.gen()).collect();
+        let walkers: Vec<Walker> = seeds.into_par_iter()
+            .enumerate()
+            .filter_map(|(id, seed)| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                let mut first = start.clone().filter(|_| id == 0);
+                if let Some(c) = first.as_mut().filter(|c| c.energy.is_none()) {
+                    if self.relax(c).is_err() { first = None; }
                 }
-            }
+                let current = first.or_else(|| self.random_start(&mut rng))?;
+                Some(Walker {
+                    id,
+                    best: self.hashed(&current),
+                    current,
+                    rng,
+                    control: StepController::new(&self.params),
+                    criterion: acceptance::build(&self.params),
+                    stagnation: 0,
+                    steps: 0,
+                    accepted: 0,
+                    restarts: 0,
+                    failures: FailureTally::default(),
+                })
+            })
+            .collect();
+
+        if walkers.is_empty() {
+            bail!("CRITICAL: No walker could be started.");
         }
+        Ok(walkers)
+    }
 
-        let mut best = current.clone();
-        if let Some(_) = best.energy {
-            let _ = tx.send(SolverEvent::NewBest(best.clone()));
-        }
-        
-        let start_time = Instant::now();
-        let mut accepted_count = 0;
-
-        // 2. Main Loop
-        for i in 1..=self.params.max_steps {
-            // A. Perturb
-            // Standard BH move: Random translation + slight rotation to escape shallow wells
-            let mut trial = Mutator::new()
-                .translate(self.params.step_size)
-                .rotate(0.2) 
-                .apply(&current, &mut rng);
-            
-            trial.origin = format!("BH_{}", i);
-
-            // B. Pre-check Geometry
-            // If the move creates an overlap (collision), reject immediately (infinite energy)
-            if !spatial::check_overlap(&trial, &self.grid) {
-                // Report current state (Rejection)
-                self.report_step(&tx, i, &current);
-                continue;
+    /// One move of the single walker.
+    fn hop(&self, run: &mut Run, tx: &Sender<SolverEvent>) {
+        let i = run.done + 1;
+        let w = &mut run.walkers[0];
+        let outcome = self.step(&mut w.current, w.control.temperature, w.control.step_size, i, &mut w.rng, w.criterion.as_mut());
+        w.control.record(outcome == Move::Accepted);
+        w.steps = i;
+        match outcome {
+            Move::Accepted => {
+                w.accepted += 1;
+                // Check Global Best
+                if w.current.energy < w.best.energy {
+                    w.best = w.current.clone();
+                    let _ = tx.send(SolverEvent::NewBest(w.best.clone()));
+                }
             }
+            Move::Rejected => {}
+            Move::Failed(kind) => w.failures.record(kind),
+        }
+        run.done = i;
 
-            // C. Local Minimization
-            match self.evaluator.evaluate(&trial) {
-                Ok(res) => {
-                    // Update trial with relaxed energy/geometry
-                    trial.energy = Some(res.energy);
-                    
-                    if let Some(geom) = res.relaxed_cluster {
-                        if geom.atoms.len() == trial.atoms.len() {
-                            for (orig, new) in trial.atoms.iter_mut().zip(geom.atoms.iter()) {
-                                orig.position = new.position;
-                            }
-                            if geom.lattice.is_some() { trial.lattice = geom.lattice; }
-                            spatial::wrap_or_center(&mut trial);
-                        } else {
-                            // Atom count mismatch from engine -> Reject
-                            self.report_step(&tx, i, &current);
+        // Report stats (Current position of walker)
+        self.report_step(tx, i, &w.current, &w.control, &w.failures);
+    }
+
+    /// Multi-walker mode: every walker takes `WALKER_BLOCK` steps in parallel (fewer if the run
+    /// is stopped), then the walkers are synchronised for the shared Hall of Fame, restarts and
+    /// reporting.
+    ///
+    /// With `restart_after > 0`, a walker that has not improved its own best for that many steps
+    /// restarts from a new random cluster if another walker found that best first: its funnel
+    /// is already covered by the Hall of Fame.
+    fn walker_block(&self, run: &mut Run, tx: &Sender<SolverEvent>, control: &Control) {
+        let end = (run.done + WALKER_BLOCK).min(self.params.max_steps);
+
+        // A stop can cut the block short; each walker carries on from its own step count
+        run.walkers.par_iter_mut().for_each(|w| {
+            while w.steps < end {
+                if !control.checkpoint() { break; }
+                w.steps += 1;
+                let outcome = self.step(&mut w.current, w.control.temperature, w.control.step_size, w.steps, &mut w.rng, w.criterion.as_mut());
+                w.control.record(outcome == Move::Accepted);
+                match outcome {
+                    Move::Accepted => {
+                        w.accepted += 1;
+                        if w.current.energy < w.best.energy {
+                            w.best = self.hashed(&w.current);
+                            w.stagnation = 0;
                             continue;
                         }
                     }
-                    trial.status = ClusterStatus::Evaluated;
-
-                    // D. Metropolis Acceptance
-                    let e_new = res.energy;
-                    let e_old = current.energy.unwrap_or(f64::MAX);
-
-                    let accepted = if e_new < e_old {
-                        true
-                    } else {
-                        let delta = e_new - e_old;
-                        let temp = self.params.temperature;
-                        if temp <= 1e-9 {
-                            false // Quench only
-                        } else {
-                            let prob = (-delta / (kb_ev * temp)).exp();
-                            rng.gen::<f64>() < prob
-                        }
-                    };
-
-                    if accepted {
-                        accepted_count += 1;
-                        current = trial; // Move walker
-
-                        // Check Global Best
-                        if let Some(best_e) = best.energy {
-                            if e_new < best_e {
-                                best = current.clone();
-                                let _ = tx.send(SolverEvent::NewBest(best.clone()));
-                            }
-                        }
-                    }
+                    Move::Rejected => {}
+                    Move::Failed(kind) => w.failures.record(kind),
+                }
+                w.stagnation += 1;
+            }
+        });
+        run.done = run.walkers.iter().map(|w| w.steps).min().unwrap_or(end);
 
-                    // Report stats (Current position of walker)
-                    self.report_step(&tx, i, &current);
-                },
-                Err(_) => {
-                    // Physics engine failed (e.g. SCF did not converge) -> Reject move
-                    self.report_step(&tx, i, &current);
+        self.publish(&run.walkers, &mut run.hof, tx);
+
+        if self.params.restart_after > 0 {
+            for w in run.walkers.iter_mut().filter(|w| w.stagnation >= self.params.restart_after) {
+                let finder = run.hof.finder_of(w.best.hash_key.as_deref()).filter(|&id| id != w.id);
+                let Some(finder) = finder else { continue; };
+                if let Some(fresh) = self.random_start(&mut w.rng) {
+                    w.best = self.hashed(&fresh);
+                    w.current = fresh;
+                    w.stagnation = 0;
+                    w.restarts += 1;
+                    let _ = tx.send(SolverEvent::Log(format!("Walker {} stuck in the funnel of walker {}. Restarting.", w.id, finder)));
                 }
             }
         }
 
-        let duration = start_time.elapsed().as_secs_f64();
-        let rate = if duration > 0.0 { self.params.max_steps as f64 / duration } else { 0.0 };
-        
-        let _ = tx.send(SolverEvent::Log(format!("BH Finished. Acceptance: {}/{}", accepted_count, self.params.max_steps)));
-        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
-        let _ = tx.send(SolverEvent::Finished);
+        self.report_walkers(tx, run.done, &run.walkers, &run.hof);
+    }
+
+    /// A relaxed random cluster, or `None` after `START_ATTEMPTS` failures.
+    fn random_start(&self, rng: &mut impl Rng) -> Option<Cluster> {
+        (0..START_ATTEMPTS).find_map(|_| {
+            let mut c = Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, rng)?;
+            self.relax(&mut c).ok().map(|_| c)
+        })
+    }
+
+    fn hashed(&self, cluster: &Cluster) -> Cluster {
+        let mut c = cluster.clone();
+        c.hash_key = Some(topology::generate_hash_key(&c, 1.5));
+        c
+    }
+
+    /// Adds every walker's best to the shared Hall of Fame; new entries go to the UI.
+    fn publish(&self, walkers: &[Walker], hof: &mut HallOfFame, tx: &Sender<SolverEvent>) {
+        for w in walkers {
+            if hof.insert(&w.best, w.id) {
+                let _ = tx.send(SolverEvent::NewBest(w.best.clone()));
+            }
+        }
+    }
+
+    fn report_walkers(&self, tx: &Sender<SolverEvent>, step: usize, walkers: &[Walker], hof: &HallOfFame) {
+        let energies: Vec<f64> = walkers.iter().filter_map(|w| w.current.energy).collect();
+        let (avg, worst) = if energies.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (energies.iter().sum::<f64>() / energies.len() as f64, energies.iter().cloned().fold(f64::MIN, f64::max))
+        };
+        let unique: HashSet<String> = walkers.iter()
+            .map(|w| topology::generate_hash_key(&w.current, 1.5))
+            .collect();
+
+        let mut failures = FailureTally::default();
+        for w in walkers {
+            failures.merge(&w.failures);
+        }
+        let k = walkers.len() as f64;
+        let step_size = walkers.iter().map(|w| w.control.step_size).sum::<f64>() / k;
+        let temperature = walkers.iter().map(|w| w.control.temperature).sum::<f64>() / k;
+        let acceptance = walkers.iter().map(|w| w.control.acceptance()).sum::<f64>() / k;
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: step,
+            best_energy: hof.best().and_then(|c| c.energy).unwrap_or(0.0),
+            avg_energy: avg,
+            worst_energy: worst,
+            diversity: unique.len() as f64 / walkers.len() as f64,
+            valid_count: energies.len(),
+            pop_size: walkers.len(),
+            mutation_rate: 0.0,
+            step_size: Some(step_size),
+            temperature: Some(temperature),
+            acceptance: Some(acceptance),
+            failures,
+        }));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+
+    /// Relaxes `cluster` in place, adopting the engine's energy and geometry.
+    pub(crate) fn relax(&self, cluster: &mut Cluster) -> Result<()> {
+        solvers::relax_in_place(self.evaluator.as_ref(), cluster)
+    }
+
+    /// One basin-hopping move of `current` at `temperature` (K) with displacements of up to
+    /// `step_size` (Å), judged by `criterion`. On acceptance `current` is replaced by the relaxed trial.
+    pub(crate) fn step(
+        &self,
+        current: &mut Cluster,
+        temperature: f64,
+        step_size: f64,
+        iter: usize,
+        rng: &mut impl Rng,
+        criterion: &mut dyn AcceptanceCriterion,
+    ) -> Move {
+        // A. Perturb
+        // Per-atom moves only: a rigid translation or rotation leaves the energy unchanged
+        let mv = self.choose_move(current, rng);
+        let mutator = match mv {
+            BhMove::Rattle => Mutator::new().rattle(step_size),
+            BhMove::Surface => Mutator::new().surface_rattle(step_size),
+            BhMove::Swap => Mutator::new().exchange(1),
+        };
+        let mut trial = mutator.apply(current, rng);
+
+        trial.origin = format!("BH_{}_{}", mv.name(), iter);
+
+        // B. Pre-check Geometry
+        // If the move creates an overlap (collision), reject immediately (infinite energy)
+        if !spatial::check_overlap(&trial, &self.grid) {
+            return Move::Failed(EvalFailure::Overlap);
+        }
+
+        // C. Local Minimization
+        // Physics engine failures (e.g. SCF did not converge) reject the move
+        if let Err(e) = self.relax(&mut trial) {
+            return Move::Failed(classify_failure(&e));
+        }
+
+        // D. Acceptance
+        if criterion.accept(current, &trial, temperature, rng) {
+            *current = trial; // Move walker
+            Move::Accepted
+        } else {
+            Move::Rejected
+        }
+    }
+
+    /// Draws a move class from `params.bh_moves` by weight. Swaps are skipped for
+    /// single-species clusters; with nothing left the move is a rattle.
+    fn choose_move(&self, cluster: &Cluster, rng: &mut impl Rng) -> BhMove {
+        let mixed = cluster.atoms.windows(2).any(|w| w[0].element_id != w[1].element_id);
+        let moves: Vec<(BhMove, f64)> = self.params.bh_moves.iter()
+            .copied()
+            .filter(|&(mv, w)| w > 0.0 && (mixed || mv != BhMove::Swap))
+            .collect();
+        let total: f64 = moves.iter().map(|(_, w)| w).sum();
+
+        let mut r = rng.gen::<f64>() * total;
+        for &(mv, w) in &moves {
+            if r < w {
+                return mv;
+            }
+            r -= w;
+        }
+        moves.last().map_or(BhMove::Rattle, |&(mv, _)| mv)
     }
 
-    fn report_step(&self, tx: &Sender<SolverEvent>, iter: usize, cluster: &Cluster) {
+    /// Maps the walker onto `GenStats`.
+    fn report_step(&self, tx: &Sender<SolverEvent>, iter: usize, cluster: &Cluster, control: &StepController, failures: &FailureTally) {
         let e = cluster.energy.unwrap_or(0.0);
-        
+
         // Map single walker to population stats
         let stats = GenStats {
             generation: iter,
@@ -177,9 +468,92 @@ impl BasinHopping {
             diversity: 1.0, // A population of 1 is always 100% diverse relative to itself
             valid_count: 1,
             pop_size: 1,
-            mutation_rate: 0.0, // BH uses fixed step_size, not mutation rate
+            mutation_rate: 0.0,
+            step_size: Some(control.step_size),
+            temperature: Some(control.temperature),
+            acceptance: Some(control.acceptance()),
+            failures: failures.clone(),
         };
 
         let _ = tx.send(SolverEvent::GenerationUpdate(stats));
+        solvers::report_evaluator_every(self.evaluator.as_ref(), tx, iter);
+    }
+}
+
+impl Solver for BasinHopping {
+    fn name(&self) -> &str { "Basin Hopping" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        // Defensive: Validate inputs
+        if self.params.max_steps == 0 {
+            bail!("Max steps set to 0. Exiting.");
+        }
+
+        let walkers = if self.params.walkers > 1 { self.start_walkers(tx)? } else { self.start_walker(tx)? };
+        let mut hof = HallOfFame::new(HOF_CAPACITY);
+        if walkers.len() > 1 {
+            self.publish(&walkers, &mut hof, tx);
+        }
+        self.run = Some(Run { walkers, hof, done: 0, start_time: Instant::now() });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = if run.done >= self.params.max_steps {
+            Progress::Done
+        } else {
+            if self.params.walkers > 1 { self.walker_block(&mut run, tx, control); } else { self.hop(&mut run, tx); }
+            if run.done < self.params.max_steps { Progress::Continue } else { Progress::Done }
+        };
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        let Some(run) = &self.run else { return SolverState::default(); };
+        let best = if self.params.walkers > 1 {
+            run.hof.best().cloned()
+        } else {
+            run.walkers.first().map(|w| w.best.clone())
+        };
+        SolverState { step: run.done, best }
     }
-}
\ No newline at end of file
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let steps: usize = run.walkers.iter().map(|w| w.steps).sum();
+        let rate = if duration > 0.0 { steps as f64 / duration } else { 0.0 };
+        let accepted: usize = run.walkers.iter().map(|w| w.accepted).sum();
+
+        if self.params.walkers > 1 {
+            let restarts: usize = run.walkers.iter().map(|w| w.restarts).sum();
+            let _ = tx.send(SolverEvent::Log(format!(
+                "BH Finished. {} walkers, acceptance {}/{}, {} restarts, {} distinct minima",
+                run.walkers.len(), accepted, steps, restarts, run.hof.len()
+            )));
+        } else {
+            let _ = tx.send(SolverEvent::Log(format!("BH Finished. Acceptance: {}/{}", accepted, steps)));
+        }
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+
+    fn tune(&mut self, tuning: &Tuning) -> Result<String> {
+        let Some(run) = self.run.as_mut() else { bail!("BH has not started yet"); };
+        match *tuning {
+            Tuning::Temperature(t) => {
+                self.params.temperature = t.max(0.0);
+                run.walkers.iter_mut().for_each(|w| w.control.temperature = self.params.temperature);
+                Ok(format!("BH temperature set to {:.1} K", self.params.temperature))
+            }
+            Tuning::StepSize(step) => {
+                self.params.step_size = step.max(MIN_STEP);
+                run.walkers.iter_mut().for_each(|w| w.control.step_size = self.params.step_size);
+                Ok(format!("BH step size set to {:.2} Å", self.params.step_size))
+            }
+            _ => bail!("BH does not support live changes to the {}", tuning.name()),
+        }
+    }
+}
@@ -1,22 +1,198 @@
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
 use std::time::Instant;
-use crossbeam_channel::Sender;
+use anyhow::{bail, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
 use rand::prelude::*;
 use rayon::prelude::*;
+use rand_chacha::ChaCha8Rng;
 
-use crate::core::domain::{Cluster, Params, ClusterStatus};
+use crate::core::domain::{Cluster, Params, ClusterStatus, EvalFailure, GaMode, MigrationTopology};
 use crate::core::spatial;
 use crate::core::chemistry::InteractionGrid;
-use crate::engine::evaluator::Evaluator;
-use crate::engine::operators::{Mutator, crossover_cut_splice};
-use crate::analysis::topology;
-use crate::solvers::{SolverEvent, GenStats};
+use crate::engine::evaluator::{Evaluator, classify_failure};
+use crate::engine::operators::{Mutator, MutationOp, crossover_cut_splice};
+use crate::analysis::{pareto, topology};
+use crate::solvers::acceptance::Metropolis;
+use crate::solvers::bh::{BasinHopping, Move};
+use crate::solvers::hof::ParetoFront;
+use crate::solvers::aos::{self, OperatorPool, REWARD_BEST, REWARD_ENTERED};
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, Tuning, GenStats, FailureTally, seeded_rng, report_evaluator};
+
+/// Adaptive state machine shared by the generational and steady-state modes.
+/// Tracks stagnation and decides on hyper-mutation or mass extinction.
+struct Adaptation {
+    base_rate: f64,
+    mutation_rate: f64,
+    stagnation: usize,
+    cooldown: usize,
+    best_energy: f64,
+    /// Learned mutation operator probabilities (`None` = always the classic recipe).
+    operators: Option<OperatorPool>,
+}
+
+impl Adaptation {
+    fn new(base_rate: f64, best_energy: f64, operators: Option<OperatorPool>) -> Self {
+        Self {
+            base_rate,
+            mutation_rate: base_rate,
+            stagnation: 0,
+            cooldown: 0,
+            best_energy,
+            operators,
+        }
+    }
+
+    /// Credits the operator that bred a child (named by its `origin`): `REWARD_BEST` below
+    /// `best`, `REWARD_ENTERED` if it entered the population, nothing otherwise.
+    fn credit(&mut self, origin: &str, energy: Option<f64>, best: f64, entered: bool) {
+        let Some(pool) = &mut self.operators else { return; };
+        let reward = match energy {
+            Some(e) if e < best - 1e-5 => REWARD_BEST,
+            Some(_) if entered => REWARD_ENTERED,
+            _ => 0.0,
+        };
+        pool.credit(origin, reward);
+    }
+
+    /// Records the current best energy. Returns true on a new record.
+    fn record(&mut self, current_best_e: f64) -> bool {
+        if current_best_e < self.best_energy - 1e-5 {
+            self.stagnation = 0;
+            self.cooldown = 0;
+            self.mutation_rate = self.base_rate;
+            self.best_energy = current_best_e;
+            true
+        } else {
+            self.stagnation += 1;
+            false
+        }
+    }
+
+    /// Resets stagnation tracking after a mass extinction.
+    fn restart(&mut self) {
+        self.stagnation = 0;
+        self.cooldown = 50;
+        self.mutation_rate = self.base_rate;
+    }
+
+    /// Returns the reason for a mass extinction, if one should happen now.
+    fn adapt(&mut self, gen: usize, diversity: f64, tx: &Sender<SolverEvent>) -> Option<&'static str> {
+        if self.cooldown > 0 {
+            self.cooldown -= 1;
+            return None;
+        }
+
+        let catastrophic_stagnation = self.stagnation > 50;
+        let premature_convergence = gen > 20 && self.stagnation > 20 && diversity < 0.1;
+
+        if catastrophic_stagnation || premature_convergence {
+            let reason = if catastrophic_stagnation { "Stagnation 50+" } else { "Low Diversity" };
+            let _ = tx.send(SolverEvent::Log(format!("Mass Extinction ({}) -> Reseeding", reason)));
+            self.restart();
+            Some(reason)
+        } else {
+            if self.stagnation > 20 && self.mutation_rate < 0.5 {
+                let _ = tx.send(SolverEvent::Log("Stagnation (20+) -> Hyper-Mutation".to_string()));
+                self.mutation_rate = 0.5;
+            }
+            None
+        }
+    }
+}
+
+/// One subpopulation of the island model, with its own RNG stream and adaptation state.
+/// The generational and steady-state modes evolve a single one.
+struct Island {
+    population: Vec<Cluster>,
+    adaptation: Adaptation,
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    diversity: f64,
+    evals: usize,
+}
+
+/// Members kept on the Pareto front in multi-objective runs.
+const FRONT_CAPACITY: usize = 50;
 
 pub struct GeneticAlgorithm {
     evaluator: Arc<dyn Evaluator>,
     grid: Arc<InteractionGrid>,
     params: Params,
+    /// Local search for the memetic phase.
+    hopper: BasinHopping,
+    run: Option<Run>,
+}
+
+/// Evolution state between generations.
+struct Run {
+    islands: Vec<Island>,
+    front: Option<ParetoFront>,
+    gen: usize,
+    total_evals: usize,
+    /// Best energy published so far (island mode).
+    best_energy: f64,
+    /// Steady-state: extinction slots still to be refilled with randoms.
+    reseed_pending: usize,
+    /// Steady-state: evaluation workers, started by the first window.
+    pool: Option<WorkPool>,
+}
+
+/// Evaluation workers kept for a whole steady-state run, fed through a crossbeam work queue.
+/// Dropping the pool closes the queue and waits for the children still in flight.
+struct WorkPool {
+    jobs: Option<Sender<Cluster>>,
+    results: Receiver<Cluster>,
+    workers: Vec<JoinHandle<()>>,
+    /// Children submitted but not yet collected.
+    in_flight: usize,
+}
+
+impl WorkPool {
+    fn new(evaluator: Arc<dyn Evaluator>, threads: usize) -> Self {
+        let (job_tx, job_rx) = unbounded::<Cluster>();
+        let (res_tx, results) = unbounded::<Cluster>();
+        let workers = (0..threads.max(1))
+            .map(|_| {
+                let (job_rx, res_tx, evaluator) = (job_rx.clone(), res_tx.clone(), evaluator.clone());
+                thread::spawn(move || {
+                    for mut child in job_rx.iter() {
+                        let _ = solvers::relax_in_place(evaluator.as_ref(), &mut child);
+                        if res_tx.send(child).is_err() { break; }
+                    }
+                })
+            })
+            .collect();
+        Self { jobs: Some(job_tx), results, workers, in_flight: 0 }
+    }
+
+    fn submit(&mut self, child: Cluster) {
+        if let Some(jobs) = &self.jobs {
+            if jobs.send(child).is_ok() {
+                self.in_flight += 1;
+            }
+        }
+    }
+
+    /// Waits for the next finished child; None once nothing is in flight.
+    fn next(&mut self) -> Option<Cluster> {
+        if self.in_flight == 0 {
+            return None;
+        }
+        let child = self.results.recv().ok()?;
+        self.in_flight -= 1;
+        Some(child)
+    }
+}
+
+impl Drop for WorkPool {
+    fn drop(&mut self) {
+        self.jobs = None; // Workers exit once the queue drains
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
 }
 
 impl GeneticAlgorithm {
@@ -25,269 +201,757 @@ impl GeneticAlgorithm {
         grid: Arc<InteractionGrid>,
         params: Params
     ) -> Self {
-        Self { evaluator, grid, params }
+        let hopper = BasinHopping::new(evaluator.clone(), grid.clone(), params.clone());
+        Self { evaluator, grid, params, hopper, run: None }
     }
 
-    pub fn solve(&self, tx: Sender<SolverEvent>) {
-        let _start_time = Instant::now();
-        let mut rng = rand::thread_rng();
+    pub fn solve(mut self, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self, tx);
+    }
 
-        // 1. Initialization Phase
+    /// Start-up for the single-population modes: random population, evaluated, hashed and ranked.
+    fn initialize(&self, rng: &mut impl Rng, failures: &mut FailureTally, tx: &Sender<SolverEvent>) -> Result<Vec<Cluster>> {
         let _ = tx.send(SolverEvent::Log("Initializing Population...".to_string()));
-        
-        let mut population = self.generate_initial_population();
-        
+
+        let population = self.seed_population(rng, failures);
+
         if population.is_empty() {
-            let _ = tx.send(SolverEvent::Log("CRITICAL: Failed to generate valid initial population.".to_string()));
-            let _ = tx.send(SolverEvent::Finished);
-            return;
+            bail!("CRITICAL: Failed to generate valid initial population.");
         }
 
-        self.evaluate_batch(&mut population);
-        self.rank_population(&mut population);
-        
-        if let Some(best) = population.first() {
+        if let Some(best) = lowest(&population) {
             if best.energy.is_some() {
                 let _ = tx.send(SolverEvent::NewBest(best.clone()));
             }
         }
+        Ok(population)
+    }
+
+    /// Island-model start-up: one independently seeded population per island.
+    fn initialize_islands(&self, tx: &Sender<SolverEvent>) -> Result<Vec<Island>> {
+        let mut rng = seeded_rng(self.params.seed);
+        let k = self.params.islands.max(1);
+
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Initializing {} islands of {} ({:?} migration every {} generations)...",
+            k, self.params.population_size, self.params.migration_topology, self.params.migration_interval
+        )));
+        let islands: Vec<Island> = (0..k)
+            .map(|i| {
+                let mut island_rng = ChaCha8Rng::seed_from_u64(rng.gen());
+                let mut failures = FailureTally::default();
+                let population = self.seed_population(&mut island_rng, &mut failures);
+                let best = lowest(&population).and_then(|c| c.energy).unwrap_or(f64::MAX);
+                Island {
+                    population,
+                    adaptation: Adaptation::new(self.island_mutation_rate(i, k), best, self.operator_pool()),
+                    rng: island_rng,
+                    failures,
+                    diversity: 1.0,
+                    evals: 0,
+                }
+            })
+            .collect();
+
+        if islands.iter().all(|isl| isl.population.is_empty()) {
+            bail!("CRITICAL: Failed to generate valid initial population.");
+        }
+        Ok(islands)
+    }
+
+    /// Random population, evaluated, hashed and ranked. Empty if no valid cluster could be built.
+    fn seed_population(&self, rng: &mut impl Rng, failures: &mut FailureTally) -> Vec<Cluster> {
+        let mut population = self.generate_initial_population(rng);
+        if population.is_empty() {
+            return population;
+        }
+
+        self.evaluate_batch(&mut population, failures, &Control::detached());
+        for c in &mut population {
+            if c.energy.is_some() {
+                c.hash_key = Some(topology::generate_hash_key(c, 1.5));
+            }
+        }
+        self.rank_population(&mut population);
+        population
+    }
 
-        // State Tracking
-        let mut stagnation_counter = 0;
-        let mut extinction_cooldown = 0;
-        let mut last_global_best_e = population.first().and_then(|c| c.energy).unwrap_or(f64::MAX);
-        let mut total_evals = 0;
-        
-        // Dynamic Parameters
-        let mut current_mutation_rate = self.params.mutation_rate;
+    /// One generation of the generational GA.
+    fn generation(&self, run: &mut Run, tx: &Sender<SolverEvent>, control: &Control) {
+        let gen = run.gen;
+        let gen_start = Instant::now();
+        let island = &mut run.islands[0];
 
-        // 2. Evolution Loop
-        for gen in 1..=self.params.max_steps {
-            let gen_start = Instant::now();
+        // A-D. Breed, evaluate, deduplicate, refill and rank
+        let population = std::mem::take(&mut island.population);
+        let (next, diversity, mut evals_this_gen) = self.evolve(population, &mut island.adaptation, gen, &mut island.rng, &mut island.failures, control);
+        island.population = next;
 
-            // A. Breeding
-            let mut children = Vec::with_capacity(self.params.population_size);
-            let breeding_target = self.params.population_size.saturating_sub(self.params.elitism_count);
+        // D2. Memetic local search on the elites
+        if self.memetic_due(gen) && !control.stopped() {
+            let (improved, evals) = self.memetic(island, control);
+            evals_this_gen += evals;
+            let _ = tx.send(SolverEvent::Log(format!("Memetic (gen {}): {} elites improved", gen, improved)));
+        }
+        run.total_evals += evals_this_gen;
 
-            if !population.is_empty() {
-                while children.len() < breeding_target {
-                    let p1 = self.tournament_select(&population, &mut rng);
-                    let p2 = self.tournament_select(&population, &mut rng);
+        // E. Stagnation Logic
+        let current_best_e = lowest(&island.population).and_then(|c| c.energy).unwrap_or(f64::MAX);
 
-                    // Crossover
-                    let mut child = if rng.gen::<f64>() < self.params.crossover_rate {
-                        crossover_cut_splice(p1, p2, &mut rng).unwrap_or_else(|| p1.clone())
-                    } else {
-                        p1.clone()
-                    };
-
-                    // Mutation
-                    if rng.gen::<f64>() < current_mutation_rate {
-                        let rattle_mag = if stagnation_counter > 20 { 0.3 } else { 0.1 };
-                        
-                        let mut mutator = Mutator::new()
-                            .rotate(0.5)
-                            .rattle(rattle_mag)
-                            .swap(1);
-                        
-                        if rng.gen_bool(0.2) {
-                            mutator = mutator.breathing(0.05);
-                        }
-                        
-                        child = mutator.apply(&child, &mut rng);
+        if island.adaptation.record(current_best_e) {
+            if let Some(best) = lowest(&island.population) {
+                let _ = tx.send(SolverEvent::NewBest(best.clone()));
+            }
+        }
+
+        // Adaptive State Machine
+        if island.adaptation.adapt(gen, diversity, tx).is_some() && !control.stopped() {
+            self.extinction(&mut island.population, &mut island.rng, &mut island.failures);
+        }
+        self.publish_front(&mut run.front, &island.population, tx);
+
+        // F. Telemetry
+        self.report_generation(tx, gen, &island.population, diversity, island.adaptation.mutation_rate, &island.failures);
+        report_operators(tx, &[&island.adaptation]);
+
+        let duration = gen_start.elapsed().as_secs_f64();
+        if duration > 0.0 {
+            let ops = evals_this_gen as f64 / duration;
+            let _ = tx.send(SolverEvent::WorkerHeartbeat(ops));
+        }
+    }
+
+    /// One generation of the island-model GA.
+    ///
+    /// `islands` generational subpopulations of `population_size` evolve in parallel, each with
+    /// its own RNG stream, adaptation state and mutation rate (spread geometrically by
+    /// `mutation_spread`). Every `migration_interval` generations each island sends copies of its
+    /// `migration_size` best members along the migration topology, where they replace the worst
+    /// members unless they duplicate an isomer already there. Telemetry reports all islands merged.
+    fn island_generation(&self, run: &mut Run, tx: &Sender<SolverEvent>, control: &Control) {
+        let gen = run.gen;
+        let gen_start = Instant::now();
+        let k = run.islands.len();
+
+        // 1. Every island takes one generational step (and memetic phase) in parallel
+        let memetic = self.memetic_due(gen);
+        let improved: usize = run.islands.par_iter_mut().map(|island| {
+            let population = std::mem::take(&mut island.population);
+            let (next, diversity, evals) = self.evolve(population, &mut island.adaptation, gen, &mut island.rng, &mut island.failures, control);
+            island.population = next;
+            island.diversity = diversity;
+            island.evals = evals;
+
+            let mut improved = 0;
+            if memetic && !control.stopped() {
+                let (n, evals) = self.memetic(island, control);
+                island.evals += evals;
+                improved = n;
+            }
+
+            island.adaptation.record(lowest(&island.population).and_then(|c| c.energy).unwrap_or(f64::MAX));
+            if island.adaptation.adapt(gen, diversity, tx).is_some() && !control.stopped() {
+                self.extinction(&mut island.population, &mut island.rng, &mut island.failures);
+            }
+            improved
+        }).sum();
+        if memetic {
+            let _ = tx.send(SolverEvent::Log(format!("Memetic (gen {}): {} elites improved across {} islands", gen, improved, k)));
+        }
+
+        // 2. Migration
+        if k > 1 && self.params.migration_interval > 0 && gen.is_multiple_of(self.params.migration_interval) {
+            let settled = self.migrate(&mut run.islands);
+            let bests: Vec<String> = run.islands.iter()
+                .map(|isl| lowest(&isl.population).and_then(|c| c.energy).map_or("-".to_string(), |e| format!("{:.4}", e)))
+                .collect();
+            let _ = tx.send(SolverEvent::Log(format!(
+                "Migration (gen {}): {} immigrants settled. Island bests: [{}]",
+                gen, settled, bests.join(", ")
+            )));
+        }
+
+        self.publish_best(&run.islands, &mut run.best_energy, tx);
+        self.publish_front(&mut run.front, run.islands.iter().flat_map(|isl| &isl.population), tx);
+
+        // 3. Telemetry (all islands merged)
+        let evals_this_gen: usize = run.islands.iter().map(|isl| isl.evals).sum();
+        run.total_evals += evals_this_gen;
+        self.report_islands(tx, gen, &run.islands);
+
+        let duration = gen_start.elapsed().as_secs_f64();
+        if duration > 0.0 {
+            let _ = tx.send(SolverEvent::WorkerHeartbeat(evals_this_gen as f64 / duration));
+        }
+    }
+
+    /// One pseudo-generation of the asynchronous steady-state GA.
+    ///
+    /// Keeps `threads` evaluations in flight on the run's work pool and inserts each
+    /// finished child into the population immediately (replace-worst, no duplicate isomers).
+    /// Every `population_size - elitism_count` completed children count as one generation
+    /// for telemetry and adaptation, so the evaluation budget matches the generational mode.
+    /// The queue stays full across windows, so there is no barrier between generations.
+    /// Diversity is the fraction of those children that were new isomers.
+    fn steady_state_window(&self, run: &mut Run, tx: &Sender<SolverEvent>, control: &Control) {
+        let gen = run.gen;
+        let window = self.params.population_size.saturating_sub(self.params.elitism_count).max(1);
+        let in_flight_target = self.params.threads.max(1);
+        let island = &mut run.islands[0];
+        let pool = run.pool.get_or_insert_with(|| WorkPool::new(self.evaluator.clone(), in_flight_target));
+
+        let mut completed = 0;
+        let window_start = Instant::now();
+        let mut window_evals = 0;
+        let mut window_novel = 0;
+        let mut starved = false;
+
+        while completed < window {
+            // Top up the queue (children still in flight from the last window count)
+            while !starved && pool.in_flight < in_flight_target && control.checkpoint() {
+                match self.next_candidate(&island.population, &island.adaptation, &mut run.reseed_pending, gen, &mut island.failures, &mut island.rng) {
+                    Some(child) => pool.submit(child),
+                    None => starved = true,
+                }
+            }
+            let Some(mut child) = pool.next() else { break; };
+            completed += 1;
+
+            if let ClusterStatus::Discarded(kind) = child.status {
+                island.failures.record(kind);
+                island.adaptation.credit(&child.origin, None, f64::MAX, false);
+            }
+
+            if child.status == ClusterStatus::Evaluated {
+                window_evals += 1;
+                child.hash_key = Some(topology::generate_hash_key(&child, 1.5));
+
+                let best_e = lowest(&island.population).and_then(|b| b.energy).unwrap_or(f64::MAX);
+                let is_record = child.energy.is_some_and(|e| e < best_e - 1e-5);
+                if self.find_twin(&island.population, &child).is_none() {
+                    window_novel += 1;
+                }
+                self.publish_front(&mut run.front, std::slice::from_ref(&child), tx);
+                let (origin, energy) = (child.origin.clone(), child.energy);
+                let entered = self.insert_steady_state(&mut island.population, child);
+                island.adaptation.credit(&origin, energy, best_e, entered);
+                if entered && run.reseed_pending > 0 {
+                    run.reseed_pending -= 1;
+                }
+                if is_record {
+                    if let Some(best) = lowest(&island.population) {
+                        let _ = tx.send(SolverEvent::NewBest(best.clone()));
                     }
+                }
+            }
+        }
 
-                    if spatial::check_overlap(&child, &self.grid) {
-                        child.status = ClusterStatus::Born;
+        if starved {
+            let _ = tx.send(SolverEvent::Log(format!(
+                "Steady state (gen {}): no viable candidate in {} attempts, window cut short at {} children",
+                gen, self.params.population_size * 100, completed
+            )));
+        }
+
+        // Memetic local search on the elites
+        if self.memetic_due(gen) && !control.stopped() {
+            let best_e = lowest(&island.population).and_then(|c| c.energy).unwrap_or(f64::MAX);
+            let (improved, evals) = self.memetic(island, control);
+            window_evals += evals;
+            let _ = tx.send(SolverEvent::Log(format!("Memetic (gen {}): {} elites improved", gen, improved)));
+            if lowest(&island.population).and_then(|c| c.energy).is_some_and(|e| e < best_e - 1e-5) {
+                if let Some(best) = lowest(&island.population) {
+                    let _ = tx.send(SolverEvent::NewBest(best.clone()));
+                }
+            }
+        }
+        run.total_evals += window_evals;
+
+        // Pseudo-generation boundary: adapt and report
+        let diversity = window_novel as f64 / window as f64;
+        let current_best_e = lowest(&island.population).and_then(|c| c.energy).unwrap_or(f64::MAX);
+        island.adaptation.record(current_best_e);
+        if let Some(pool) = &mut island.adaptation.operators { pool.update(); }
+
+        if island.adaptation.adapt(gen, diversity, tx).is_some() {
+            island.population.truncate(self.params.elitism_count);
+            run.reseed_pending = self.params.population_size.saturating_sub(island.population.len());
+        }
+
+        self.report_generation(tx, gen, &island.population, diversity, island.adaptation.mutation_rate, &island.failures);
+        report_operators(tx, &[&island.adaptation]);
+
+        let duration = window_start.elapsed().as_secs_f64();
+        if duration > 0.0 {
+            let _ = tx.send(SolverEvent::WorkerHeartbeat(window_evals as f64 / duration));
+        }
+    }
+
+    // --- Helpers ---
+
+    /// One generational step: breed, evaluate, hash, deduplicate, refill and rank.
+    /// Returns the next population, its diversity and the number of successful evaluations.
+    fn evolve(
+        &self,
+        population: Vec<Cluster>,
+        adaptation: &mut Adaptation,
+        gen: usize,
+        rng: &mut impl Rng,
+        failures: &mut FailureTally,
+        control: &Control,
+    ) -> (Vec<Cluster>, f64, usize) {
+        let best = lowest(&population).and_then(|c| c.energy).unwrap_or(f64::MAX);
+        // Under NSGA-II order the last member need not have the highest energy
+        let worst = population.iter().filter_map(|c| c.energy).reduce(f64::max).unwrap_or(f64::MAX);
+
+        // A. Breeding
+        let mut children = Vec::with_capacity(self.params.population_size);
+        let breeding_target = self.params.population_size.saturating_sub(self.params.elitism_count);
+
+        if !population.is_empty() {
+            while children.len() < breeding_target {
+                match self.breed(&population, adaptation, rng) {
+                    Some(mut child) => {
                         child.generation = gen as u64;
                         children.push(child);
                     }
+                    None => failures.record(EvalFailure::Overlap),
+                }
+            }
+        }
+
+        // B. Elitism - Move from previous population to avoid cloning
+        let mut next_gen = Vec::with_capacity(self.params.population_size);
+        next_gen.extend(population.into_iter().take(self.params.elitism_count));
+        next_gen.extend(children);
+
+        // C. Evaluation
+        let evals = self.evaluate_batch(&mut next_gen, failures, control);
+
+        // D. Topology & Diversity
+        for c in &mut next_gen {
+            if c.energy.is_some() {
+                // Use updated topology with PMOI + Graph Spectrum
+                c.hash_key = Some(topology::generate_hash_key(c, 1.5));
+            }
+        }
+
+        // Operator credit: a child enters if it is a new isomer below the old worst (replace-worst rule)
+        if adaptation.operators.is_some() {
+            let mut seen = HashSet::new();
+            for c in &next_gen {
+                let novel = c.hash_key.as_ref().is_none_or(|h| seen.insert(h.clone()));
+                if c.generation == gen as u64 {
+                    let entered = novel && c.energy.is_some_and(|e| e < worst);
+                    adaptation.credit(&c.origin, c.energy, best, entered);
                 }
             }
+            if let Some(pool) = &mut adaptation.operators { pool.update(); }
+        }
+
+        // Deduplicate (Remove Isomers)
+        let (mut unique_pop, diversity) = self.deduplicate_population(next_gen);
+
+        // --- SMART REFILL STRATEGY ---
+        // If deduplication removed individuals, fill the gap with Mutated Survivors
+        // instead of random trash. This maintains energy quality while forcing geometric diversity.
+        let target_size = self.params.population_size;
+
+        if unique_pop.is_empty() {
+            // Catastrophic collapse (should not happen with elitism, but safe fallback)
+            unique_pop = self.generate_initial_population(rng);
+            self.evaluate_batch(&mut unique_pop, failures, control);
+        } else if unique_pop.len() < target_size {
+            let needed = target_size - unique_pop.len();
+            let mut refill = Vec::with_capacity(needed);
+
+            // Cycle through survivors (Best -> Worst -> Best...)
+            {
+                let mut source_iter = unique_pop.iter().cycle();
 
-            // B. Elitism - Move from previous population to avoid cloning
-            let mut next_gen = Vec::with_capacity(self.params.population_size);
-            next_gen.extend(population.into_iter().take(self.params.elitism_count));
-            next_gen.extend(children);
+                while refill.len() < needed {
+                    if let Some(parent) = source_iter.next() {
+                        // Apply HEAVY mutation to force it into a new topological basin
+                        // Twist + Rotate + Rattle
+                        let mut child = Mutator::new()
+                            .rotate(std::f64::consts::PI)   // Full rotation potential
+                            .twist(0.5)     // Significant twist
+                            .rattle(0.2)    // Shake atoms
+                            .apply(parent, rng);
 
-            // C. Evaluation
-            let evals_this_gen = self.evaluate_batch(&mut next_gen);
-            total_evals += evals_this_gen;
+                        child.origin = "Refill".to_string();
+                        child.status = ClusterStatus::Born;
+                        child.energy = None; // Force re-eval
+                        refill.push(child);
+                    }
+                }
+            }
+
+            // Evaluate the refill batch
+            self.evaluate_batch(&mut refill, failures, control);
 
-            // D. Topology & Diversity
-            for c in &mut next_gen {
+            // Calculate hashes for refill to ensure they are tracked correctly next gen
+            for c in &mut refill {
                 if c.energy.is_some() {
-                    // Use updated topology with PMOI + Graph Spectrum
-                    c.hash_key = Some(topology::generate_hash_key(c, 1.5)); 
+                    c.hash_key = Some(topology::generate_hash_key(c, 1.5));
                 }
             }
 
-            // Deduplicate (Remove Isomers)
-            let (mut unique_pop, diversity) = self.deduplicate_population(next_gen);
-
-            // --- SMART REFILL STRATEGY ---
-            // If deduplication removed individuals, fill the gap with Mutated Survivors
-            // instead of random trash. This maintains energy quality while forcing geometric diversity.
-            let target_size = self.params.population_size;
-            
-            if unique_pop.is_empty() {
-                // Catastrophic collapse (should not happen with elitism, but safe fallback)
-                unique_pop = self.generate_initial_population();
-                self.evaluate_batch(&mut unique_pop);
-            } else if unique_pop.len() < target_size {
-                let needed = target_size - unique_pop.len();
-                let mut refill = Vec::with_capacity(needed);
-                
-                // Cycle through survivors (Best -> Worst -> Best...)
-                {
-                    let mut source_iter = unique_pop.iter().cycle();
-
-                    while refill.len() < needed {
-                        if let Some(parent) = source_iter.next() {
-                            // Apply HEAVY mutation to force it into a new topological basin
-                            // Twist + Rotate + Rattle
-                            let mut child = Mutator::new()
-                                .rotate(3.14)   // Full rotation potential
-                                .twist(0.5)     // Significant twist
-                                .rattle(0.2)    // Shake atoms
-                                .apply(parent, &mut rng);
-
-                            child.origin = "Refill".to_string();
-                            child.status = ClusterStatus::Born;
-                            child.energy = None; // Force re-eval
-                            refill.push(child);
-                        }
+            unique_pop.extend(refill);
+        }
+
+        // Candidates skipped by a stop request
+        unique_pop.retain(|c| c.status != ClusterStatus::Born);
+
+        self.rank_population(&mut unique_pop);
+        (unique_pop, diversity, evals)
+    }
+
+    /// Live changes between generations (see `Solver::tune`).
+    fn retune(&mut self, run: &mut Run, tuning: &Tuning) -> Result<String> {
+        match *tuning {
+            Tuning::MutationRate(rate) => {
+                self.params.mutation_rate = rate.clamp(0.0, 1.0);
+                let k = run.islands.len();
+                for (i, island) in run.islands.iter_mut().enumerate() {
+                    island.adaptation.base_rate = self.island_mutation_rate(i, k);
+                    island.adaptation.mutation_rate = island.adaptation.base_rate;
+                }
+                Ok(format!("Mutation rate set to {:.2}", self.params.mutation_rate))
+            }
+            Tuning::Extinction => {
+                for island in &mut run.islands {
+                    island.adaptation.restart();
+                    if self.params.ga_mode == GaMode::SteadyState {
+                        island.population.truncate(self.params.elitism_count);
+                        run.reseed_pending = self.params.population_size.saturating_sub(island.population.len());
+                    } else {
+                        self.extinction(&mut island.population, &mut island.rng, &mut island.failures);
                     }
                 }
+                Ok("Mass Extinction (Manual) -> Reseeding".to_string())
+            }
+            _ => bail!("The GA does not support live changes to the {}", tuning.name()),
+        }
+    }
 
-                // Evaluate the refill batch
-                self.evaluate_batch(&mut refill);
-                
-                // Calculate hashes for refill to ensure they are tracked correctly next gen
-                for c in &mut refill {
-                    if c.energy.is_some() {
-                        c.hash_key = Some(topology::generate_hash_key(c, 1.5));
+    fn operator_pool(&self) -> Option<OperatorPool> {
+        self.params.adaptive_operators.then(|| OperatorPool::new(&MutationOp::ALL))
+    }
+
+    /// Mutation rate of island `i` of `k`: geometric from `mutation_rate` on the first island
+    /// to `mutation_rate * mutation_spread` on the last, capped at 1.
+    fn island_mutation_rate(&self, i: usize, k: usize) -> f64 {
+        let spread = if self.params.mutation_spread > 0.0 { self.params.mutation_spread } else { 1.0 };
+        let t = if k > 1 { i as f64 / (k - 1) as f64 } else { 0.0 };
+        (self.params.mutation_rate * spread.powf(t)).min(1.0)
+    }
+
+    /// Sends copies of each island's `migration_size` best members along the migration topology.
+    /// Emigrants are picked before anyone receives, so the result does not depend on island order.
+    /// Returns the number of immigrants that entered a population.
+    fn migrate(&self, islands: &mut [Island]) -> usize {
+        let k = islands.len();
+        let emigrants: Vec<Vec<Cluster>> = islands.iter()
+            .map(|isl| isl.population.iter().filter(|c| c.energy.is_some()).take(self.params.migration_size).cloned().collect())
+            .collect();
+
+        let mut settled = 0;
+        for (from, group) in emigrants.into_iter().enumerate() {
+            let targets: Vec<usize> = match self.params.migration_topology {
+                MigrationTopology::Ring => vec![(from + 1) % k],
+                MigrationTopology::FullyConnected => (0..k).filter(|&to| to != from).collect(),
+            };
+            for to in targets {
+                for migrant in &group {
+                    if self.insert_steady_state(&mut islands[to].population, migrant.clone()) {
+                        settled += 1;
                     }
                 }
-                
-                unique_pop.extend(refill);
             }
+        }
+        settled
+    }
 
-            population = unique_pop;
+    /// Sends `NewBest` if some island's best beats `best_energy`.
+    fn publish_best(&self, islands: &[Island], best_energy: &mut f64, tx: &Sender<SolverEvent>) {
+        let leader = islands.iter()
+            .filter_map(|isl| lowest(&isl.population))
+            .filter(|c| c.energy.is_some())
+            .min_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal));
+        if let Some(best) = leader {
+            let e = best.energy.unwrap_or(f64::MAX);
+            if e < *best_energy - 1e-5 {
+                *best_energy = e;
+                let _ = tx.send(SolverEvent::NewBest(best.clone()));
+            }
+        }
+    }
 
-            // E. Stagnation Logic
-            self.rank_population(&mut population);
-            
-            let current_best_e = population.first().and_then(|c| c.energy).unwrap_or(f64::MAX);
+    /// Reports the islands as one population: mean diversity and mutation rate, merged failures.
+    fn report_islands(&self, tx: &Sender<SolverEvent>, gen: usize, islands: &[Island]) {
+        let mut merged: Vec<Cluster> = islands.iter().flat_map(|isl| isl.population.iter().cloned()).collect();
+        self.rank_population(&mut merged);
 
-            if current_best_e < last_global_best_e - 1e-5 {
-                stagnation_counter = 0;
-                extinction_cooldown = 0;
-                current_mutation_rate = self.params.mutation_rate;
-                last_global_best_e = current_best_e;
-                if let Some(best) = population.first() {
-                    let _ = tx.send(SolverEvent::NewBest(best.clone()));
+        let k = islands.len().max(1) as f64;
+        let diversity = islands.iter().map(|isl| isl.diversity).sum::<f64>() / k;
+        let mutation_rate = islands.iter().map(|isl| isl.adaptation.mutation_rate).sum::<f64>() / k;
+        let mut failures = FailureTally::default();
+        for isl in islands {
+            failures.merge(&isl.failures);
+        }
+
+        self.report_generation(tx, gen, &merged, diversity, mutation_rate, &failures);
+        let adaptations: Vec<&Adaptation> = islands.iter().map(|isl| &isl.adaptation).collect();
+        report_operators(tx, &adaptations);
+    }
+
+    /// Mass extinction: keeps the elites and refills the population with true randoms.
+    fn extinction(&self, population: &mut Vec<Cluster>, rng: &mut impl Rng, failures: &mut FailureTally) {
+        let keep = self.params.elitism_count;
+        if population.len() > keep {
+            population.truncate(keep);
+        }
+
+        // Fill with TRUE randoms to reset the gene pool completely
+        let mut attempts = 0;
+        while population.len() < self.params.population_size && attempts < self.params.population_size * 100 {
+            attempts += 1;
+            if let Some(mut r) = Cluster::new_random(
+                &self.params.atom_counts,
+                self.params.box_size,
+                &self.grid,
+                rng
+            ) {
+                match solvers::relax_in_place(self.evaluator.as_ref(), &mut r) {
+                    Ok(()) => population.push(r),
+                    Err(e) => failures.record(classify_failure(&e)),
                 }
-            } else {
-                stagnation_counter += 1;
             }
+        }
+        self.rank_population(population);
+    }
 
-            // Adaptive State Machine
-            if extinction_cooldown > 0 {
-                extinction_cooldown -= 1;
-            } else {
-                let catastrophic_stagnation = stagnation_counter > 50;
-                let premature_convergence = gen > 20 && stagnation_counter > 20 && diversity < 0.1;
-
-                if catastrophic_stagnation || premature_convergence {
-                    let reason = if catastrophic_stagnation { "Stagnation 50+" } else { "Low Diversity" };
-                    let _ = tx.send(SolverEvent::Log(format!("Mass Extinction ({}) -> Reseeding", reason)));
-                    
-                    let keep = self.params.elitism_count;
-                    if population.len() > keep {
-                        population.truncate(keep);
-                    }
-                    
-                    // Fill with TRUE randoms to reset the gene pool completely
-                    let mut attempts = 0;
-                    while population.len() < self.params.population_size && attempts < self.params.population_size * 100 {
-                        attempts += 1;
-                        if let Some(mut r) = Cluster::new_random(
-                            &self.params.atom_counts, 
-                            self.params.box_size, 
-                            &self.grid, 
-                            &mut rng
-                        ) {
-                            if let Ok(res) = self.evaluator.evaluate(&r) {
-                                r.energy = Some(res.energy);
-                                r.status = ClusterStatus::Evaluated;
-                                population.push(r);
+    /// Produces the next steady-state job: a random reseed while an extinction is
+    /// being refilled (or the population is empty), otherwise a bred child.
+    /// None if `population_size * 100` attempts all overlapped.
+    fn next_candidate(
+        &self,
+        population: &[Cluster],
+        adaptation: &Adaptation,
+        reseed_pending: &mut usize,
+        gen: usize,
+        failures: &mut FailureTally,
+        rng: &mut impl Rng,
+    ) -> Option<Cluster> {
+        for _ in 0..self.params.population_size * 100 {
+            if *reseed_pending > 0 || population.is_empty() {
+                if let Some(c) = Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, rng) {
+                    return Some(c);
+                }
+                continue;
+            }
+            match self.breed(population, adaptation, rng) {
+                Some(mut child) => {
+                    child.generation = gen as u64;
+                    return Some(child);
+                }
+                None => failures.record(EvalFailure::Overlap),
+            }
+        }
+        None
+    }
+
+    /// True if a memetic phase follows generation `gen`. Multi-objective runs skip it, since
+    /// the BH chains only track the energy.
+    fn memetic_due(&self, gen: usize) -> bool {
+        self.params.memetic_interval > 0
+            && self.params.memetic_elites > 0
+            && self.params.objectives.is_empty()
+            && gen.is_multiple_of(self.params.memetic_interval)
+    }
+
+    /// Memetic phase: a `memetic_steps` BH chain from each of the `memetic_elites` best unique
+    /// members, in parallel. The lowest minimum of every chain that ends below its start is
+    /// inserted by the steady-state rule (replace its twin or the worst member).
+    /// Returns the number of members improved and of successful evaluations.
+    fn memetic(&self, island: &mut Island, control: &Control) -> (usize, usize) {
+        let mut seen = HashSet::new();
+        let elites: Vec<(Cluster, ChaCha8Rng)> = island.population.iter()
+            .filter(|c| c.energy.is_some() && c.hash_key.as_ref().is_none_or(|h| seen.insert(h.clone())))
+            .take(self.params.memetic_elites)
+            .map(|c| (c.clone(), ChaCha8Rng::seed_from_u64(island.rng.gen())))
+            .collect();
+
+        let chains: Vec<(Option<Cluster>, FailureTally, usize)> = elites.into_par_iter()
+            .map(|(start, mut rng)| {
+                let mut current = start.clone();
+                let mut best = start.clone();
+                let mut failures = FailureTally::default();
+                let mut evals = 0;
+                for k in 1..=self.params.memetic_steps {
+                    if !control.checkpoint() { break; }
+                    match self.hopper.step(&mut current, self.params.temperature, self.params.step_size, k, &mut rng, &mut Metropolis) {
+                        Move::Accepted => {
+                            evals += 1;
+                            if current.energy < best.energy {
+                                best = current.clone();
                             }
                         }
-                    }
-                    self.rank_population(&mut population);
-                    
-                    stagnation_counter = 0;
-                    extinction_cooldown = 50;
-                    current_mutation_rate = self.params.mutation_rate;
-
-                } else if stagnation_counter > 20 {
-                    if current_mutation_rate < 0.5 {
-                        let _ = tx.send(SolverEvent::Log("Stagnation (20+) -> Hyper-Mutation".to_string()));
-                        current_mutation_rate = 0.5;
+                        Move::Rejected => evals += 1,
+                        Move::Failed(kind) => failures.record(kind),
                     }
                 }
+                let improved = best.energy.zip(start.energy).is_some_and(|(b, s)| b < s - 1e-5);
+                (improved.then_some(best), failures, evals)
+            })
+            .collect();
+
+        let mut improved = 0;
+        let mut evals = 0;
+        for (refined, failures, n) in chains {
+            island.failures.merge(&failures);
+            evals += n;
+            if let Some(mut c) = refined {
+                c.origin = "Memetic".to_string();
+                c.hash_key = Some(topology::generate_hash_key(&c, 1.5));
+                if self.insert_steady_state(&mut island.population, c) {
+                    improved += 1;
+                }
             }
+        }
+        (improved, evals)
+    }
 
-            // F. Telemetry
-            let valid_clusters: Vec<&Cluster> = population.iter().filter(|c| c.energy.is_some()).collect();
-            let valid_count = valid_clusters.len();
-            
-            let (best_e, worst_e, avg_e) = if valid_count > 0 {
-                let best = valid_clusters.first().unwrap().energy.unwrap();
-                let worst = valid_clusters.last().unwrap().energy.unwrap();
-                let sum: f64 = valid_clusters.iter().map(|c| c.energy.unwrap()).sum();
-                (best, worst, sum / valid_count as f64)
-            } else {
-                (0.0, 0.0, 0.0)
-            };
+    /// Inserts an evaluated child into a ranked population (replace-worst; under multi-objective
+    /// ranking the worst is the last in NSGA-II order). Duplicate isomers only replace their
+    /// twin if lower in energy. Returns true if the child entered the population.
+    fn insert_steady_state(&self, population: &mut Vec<Cluster>, child: Cluster) -> bool {
+        let Some(e) = child.energy else { return false; };
 
-            let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
-                generation: gen,
-                best_energy: best_e,
-                avg_energy: avg_e,
-                worst_energy: worst_e,
-                diversity,
-                valid_count,
-                pop_size: population.len(),
-                mutation_rate: current_mutation_rate,
-            }));
+        match self.find_twin(population, &child) {
+            Some(i) => {
+                if e < population[i].energy.unwrap_or(f64::MAX) - 1e-5 {
+                    population[i] = child;
+                } else {
+                    return false;
+                }
+            }
+            None if !self.params.objectives.is_empty() => {
+                population.push(child);
+                let order = self.nsga_order(population);
+                let rejected = population.len() > self.params.population_size
+                    && order.last() == Some(&(population.len() - 1));
+                apply_order(population, &order);
+                population.truncate(self.params.population_size);
+                return !rejected;
+            }
+            None => {
+                if population.len() >= self.params.population_size {
+                    let worst = population.last().and_then(|c| c.energy).unwrap_or(f64::MAX);
+                    if e >= worst { return false; }
+                    population.pop();
+                }
+                population.push(child);
+            }
+        }
+
+        self.rank_population(population);
+        true
+    }
 
-            let duration = gen_start.elapsed().as_secs_f64();
-            if duration > 0.0 {
-                let ops = evals_this_gen as f64 / duration;
-                let _ = tx.send(SolverEvent::WorkerHeartbeat(ops));
+    /// Index of the population member with the same isomer hash, if any.
+    fn find_twin(&self, population: &[Cluster], child: &Cluster) -> Option<usize> {
+        child.hash_key.as_ref()
+            .filter(|h| *h != "INVALID" && !h.contains("NAN"))
+            .and_then(|h| population.iter().position(|c| c.hash_key.as_ref() == Some(h)))
+    }
+
+    /// Selection + crossover + mutation. Returns `None` if the child overlaps.
+    fn breed(&self, population: &[Cluster], adaptation: &Adaptation, rng: &mut impl Rng) -> Option<Cluster> {
+        let p1 = self.tournament_select(population, rng);
+        let p2 = self.tournament_select(population, rng);
+
+        // Crossover
+        let mut child = if rng.gen::<f64>() < self.params.crossover_rate {
+            crossover_cut_splice(p1, p2, rng).unwrap_or_else(|| p1.clone())
+        } else {
+            p1.clone()
+        };
+
+        // Mutation
+        if rng.gen::<f64>() < adaptation.mutation_rate {
+            let rattle_mag = if adaptation.stagnation > 20 { 0.3 } else { 0.1 };
+
+            match &adaptation.operators {
+                Some(pool) => {
+                    let op = pool.select(rng);
+                    child = op.mutator(rattle_mag, rng).apply(&child, rng);
+                    child.origin = op.name().to_string();
+                }
+                None => child = MutationOp::Classic.mutator(rattle_mag, rng).apply(&child, rng),
             }
         }
 
-        let _ = tx.send(SolverEvent::Log(format!("GA Finished. Total Evals: {}", total_evals)));
-        let _ = tx.send(SolverEvent::Finished);
+        if spatial::check_overlap(&child, &self.grid) {
+            child.status = ClusterStatus::Born;
+            child.energy = None;
+            child.hash_key = None;
+            Some(child)
+        } else {
+            None
+        }
     }
 
-    // --- Helpers ---
+    fn report_generation(
+        &self,
+        tx: &Sender<SolverEvent>,
+        gen: usize,
+        population: &[Cluster],
+        diversity: f64,
+        mutation_rate: f64,
+        failures: &FailureTally,
+    ) {
+        let energies: Vec<f64> = population.iter().filter_map(|c| c.energy).collect();
+        let valid_count = energies.len();
 
-    fn generate_initial_population(&self) -> Vec<Cluster> {
+        let (best_e, worst_e, avg_e) = if valid_count > 0 {
+            let best = energies.iter().cloned().fold(f64::MAX, f64::min);
+            let worst = energies.iter().cloned().fold(f64::MIN, f64::max);
+            let sum: f64 = energies.iter().sum();
+            (best, worst, sum / valid_count as f64)
+        } else {
+            (0.0, 0.0, 0.0)
+        };
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: gen,
+            best_energy: best_e,
+            avg_energy: avg_e,
+            worst_energy: worst_e,
+            diversity,
+            valid_count,
+            pop_size: population.len(),
+            mutation_rate,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+
+    fn generate_initial_population(&self, rng: &mut impl Rng) -> Vec<Cluster> {
         let mut pop = Vec::new();
-        let mut rng = rand::thread_rng();
         let attempts = self.params.population_size * 50;
-        
+
         for _ in 0..attempts {
             if pop.len() >= self.params.population_size { break; }
             if let Some(c) = Cluster::new_random(
-                &self.params.atom_counts, 
-                self.params.box_size, 
-                &self.grid, 
-                &mut rng
+                &self.params.atom_counts,
+                self.params.box_size,
+                &self.grid,
+                rng
             ) {
                 pop.push(c);
             }
@@ -295,43 +959,30 @@ impl GeneticAlgorithm {
         pop
     }
 
-    fn evaluate_batch(&self, pop: &mut [Cluster]) -> usize {
-        let eval_ref = &self.evaluator;
+    /// Evaluates a single cluster in place. Returns true on success.
+    fn evaluate_one(&self, cluster: &mut Cluster) -> bool {
+        solvers::relax_in_place(self.evaluator.as_ref(), cluster).is_ok()
+    }
+
+    /// Evaluates every `Born` cluster in parallel and tallies the failures.
+    /// Returns the number of successful evaluations.
+    fn evaluate_batch(&self, pop: &mut [Cluster], failures: &mut FailureTally, control: &Control) -> usize {
         let count = Arc::new(Mutex::new(0));
 
         pop.par_iter_mut()
             .filter(|c| c.status == ClusterStatus::Born)
             .for_each(|cluster| {
-                match eval_ref.evaluate(cluster) {
-                    Ok(res) => {
-                        cluster.energy = Some(res.energy);
-                        
-                        if let Some(geom) = res.relaxed_cluster {
-                            if geom.atoms.len() == cluster.atoms.len() {
-                                for (orig, new) in cluster.atoms.iter_mut().zip(geom.atoms.iter()) {
-                                    orig.position = new.position;
-                                }
-                                if geom.lattice.is_some() {
-                                    cluster.lattice = geom.lattice;
-                                }
-                                spatial::wrap_or_center(cluster);
-                            } else {
-                                cluster.status = ClusterStatus::Discarded;
-                                cluster.energy = None;
-                                return;
-                            }
-                        }
-                        
-                        cluster.status = ClusterStatus::Evaluated;
-                        if let Ok(mut c) = count.lock() { *c += 1; }
-                    },
-                    Err(_) => {
-                        cluster.status = ClusterStatus::Discarded;
-                        cluster.energy = None;
-                    }
+                if control.checkpoint() && self.evaluate_one(cluster) {
+                    if let Ok(mut c) = count.lock() { *c += 1; }
                 }
             });
-        
+
+        for c in pop.iter() {
+            if let ClusterStatus::Discarded(kind) = c.status {
+                failures.record(kind);
+            }
+        }
+
         let final_count = *count.lock().unwrap();
         final_count
     }
@@ -341,6 +992,13 @@ impl GeneticAlgorithm {
             panic!("Tournament selection called on empty population");
         }
 
+        // Multi-objective populations are kept in NSGA-II order, so the lower index wins
+        if !self.params.objectives.is_empty() {
+            let a = rng.gen_range(0..pop.len());
+            let b = rng.gen_range(0..pop.len());
+            return &pop[a.min(b)];
+        }
+
         let mut best = &pop[rng.gen_range(0..pop.len())];
         let mut best_e = best.energy.unwrap_or(f64::MAX);
 
@@ -355,7 +1013,14 @@ impl GeneticAlgorithm {
         best
     }
 
+    /// Energy order, or NSGA-II order (front, then crowding distance) when objectives are set.
+    /// Clusters missing the energy or an objective go last.
     fn rank_population(&self, pop: &mut Vec<Cluster>) {
+        if !self.params.objectives.is_empty() {
+            let order = self.nsga_order(pop);
+            apply_order(pop, &order);
+            return;
+        }
         pop.sort_by(|a, b| {
             match (a.energy, b.energy) {
                 (Some(ea), Some(eb)) => ea.partial_cmp(&eb).unwrap_or(std::cmp::Ordering::Equal),
@@ -366,6 +1031,70 @@ impl GeneticAlgorithm {
         });
     }
 
+    /// Population indices in NSGA-II order, best first.
+    fn nsga_order(&self, pop: &[Cluster]) -> Vec<usize> {
+        let costs: Vec<Option<Vec<f64>>> = pop.iter().map(|c| self.costs(c)).collect();
+        let ranked: Vec<usize> = (0..pop.len()).filter(|&i| costs[i].is_some()).collect();
+        let points: Vec<Vec<f64>> = ranked.iter().filter_map(|&i| costs[i].clone()).collect();
+
+        let mut keys = vec![(usize::MAX, 0.0); pop.len()];
+        for (&i, key) in ranked.iter().zip(pareto::nsga_keys(&points)) {
+            keys[i] = key;
+        }
+        let mut order: Vec<usize> = (0..pop.len()).collect();
+        order.sort_by(|&a, &b| keys[a].0.cmp(&keys[b].0).then(keys[b].1.total_cmp(&keys[a].1)));
+        order
+    }
+
+    /// Minimisation-oriented objective values of an evaluated cluster.
+    fn costs(&self, c: &Cluster) -> Option<Vec<f64>> {
+        c.energy?;
+        self.params.objectives.iter().map(|o| o.cost(c)).collect()
+    }
+
+    /// Fails if an objective is reported by none of the evaluated `members`: every cluster
+    /// would rank last and the Pareto front would stay empty.
+    fn check_objectives<'a>(&self, members: impl Iterator<Item = &'a Cluster>) -> Result<()> {
+        let evaluated: Vec<&Cluster> = members.filter(|c| c.energy.is_some()).collect();
+        let Some(first) = evaluated.first() else { return Ok(()); };
+        let missing: Vec<&str> = self.params.objectives.iter()
+            .filter(|o| evaluated.iter().all(|c| o.value(c).is_none()))
+            .map(|o| o.property.as_str())
+            .collect();
+        if !missing.is_empty() {
+            let available: Vec<&str> = first.properties.keys().map(|k| k.as_str()).collect();
+            bail!(
+                "Objective(s) [{}] not reported by {} (available: energy{}{})",
+                missing.join(", "),
+                self.evaluator.name(),
+                if available.is_empty() { "" } else { ", " },
+                available.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    fn pareto_front(&self) -> Option<ParetoFront> {
+        (!self.params.objectives.is_empty()).then(|| ParetoFront::new(self.params.objectives.clone(), FRONT_CAPACITY))
+    }
+
+    /// Offers `candidates` to the Pareto front and sends it to the UI if it changed.
+    fn publish_front<'a>(
+        &self,
+        front: &mut Option<ParetoFront>,
+        candidates: impl IntoIterator<Item = &'a Cluster>,
+        tx: &Sender<SolverEvent>,
+    ) {
+        let Some(front) = front else { return; };
+        let mut changed = false;
+        for c in candidates {
+            changed |= front.insert(c);
+        }
+        if changed {
+            let _ = tx.send(SolverEvent::ParetoUpdate(front.members()));
+        }
+    }
+
     fn deduplicate_population(&self, pop: Vec<Cluster>) -> (Vec<Cluster>, f64) {
         let initial_count = pop.len();
         if initial_count == 0 { return (pop, 0.0); }
@@ -374,14 +1103,14 @@ impl GeneticAlgorithm {
         let mut seen_hashes = HashSet::new();
 
         for c in pop {
-            if c.status == ClusterStatus::Discarded || c.energy.is_none() { continue; }
-            
+            if matches!(c.status, ClusterStatus::Discarded(_)) || c.energy.is_none() { continue; }
+
             if let Some(hash) = &c.hash_key {
-                if hash == "INVALID" || hash.contains("NAN") { 
+                if hash == "INVALID" || hash.contains("NAN") {
                     unique.push(c);
-                    continue; 
+                    continue;
                 }
-                
+
                 if !seen_hashes.contains(hash) {
                     seen_hashes.insert(hash.clone());
                     unique.push(c);
@@ -391,10 +1120,112 @@ impl GeneticAlgorithm {
             }
         }
 
-        let diversity = if initial_count > 0 { 
-            unique.len() as f64 / initial_count as f64 
+        let diversity = if initial_count > 0 {
+            unique.len() as f64 / initial_count as f64
         } else { 0.0 };
-        
+
         (unique, diversity)
     }
-}
\ No newline at end of file
+}
+
+/// Sends the learned operator probabilities, merged over `adaptations` (one per island).
+fn report_operators(tx: &Sender<SolverEvent>, adaptations: &[&Adaptation]) {
+    let pools: Vec<&OperatorPool> = adaptations.iter().filter_map(|a| a.operators.as_ref()).collect();
+    if !pools.is_empty() {
+        let _ = tx.send(SolverEvent::OperatorUpdate(aos::merged_stats(&pools)));
+    }
+}
+
+/// Reorders `pop` so that position `k` holds the old member `order[k]`.
+fn apply_order(pop: &mut Vec<Cluster>, order: &[usize]) {
+    let mut slots: Vec<Option<Cluster>> = pop.drain(..).map(Some).collect();
+    pop.extend(order.iter().filter_map(|&i| slots[i].take()));
+}
+
+/// Lowest-energy member. With energy-only ranking this is the first; under multi-objective
+/// ranking the first member is merely non-dominated.
+fn lowest(population: &[Cluster]) -> Option<&Cluster> {
+    population.iter()
+        .filter(|c| c.energy.is_some())
+        .min_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+impl Solver for GeneticAlgorithm {
+    fn name(&self) -> &str { "Genetic Algorithm" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let islands = match self.params.ga_mode {
+            GaMode::Island => self.initialize_islands(tx)?,
+            GaMode::Generational | GaMode::SteadyState => {
+                let mut rng = seeded_rng(self.params.seed);
+                let mut failures = FailureTally::default();
+                let population = self.initialize(&mut rng, &mut failures, tx)?;
+                let initial_best = lowest(&population).and_then(|c| c.energy).unwrap_or(f64::MAX);
+                let adaptation = Adaptation::new(self.params.mutation_rate, initial_best, self.operator_pool());
+                vec![Island { population, adaptation, rng, failures, diversity: 1.0, evals: 0 }]
+            }
+        };
+
+        self.check_objectives(islands.iter().flat_map(|isl| &isl.population))?;
+
+        let mut run = Run {
+            islands,
+            front: self.pareto_front(),
+            gen: 0,
+            total_evals: 0,
+            best_energy: f64::MAX,
+            reseed_pending: 0,
+            pool: None,
+        };
+        if self.params.ga_mode == GaMode::Island {
+            self.publish_best(&run.islands, &mut run.best_energy, tx);
+        }
+        self.publish_front(&mut run.front, run.islands.iter().flat_map(|isl| &isl.population), tx);
+        self.run = Some(run);
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = if run.gen >= self.params.max_steps {
+            Progress::Done
+        } else {
+            run.gen += 1;
+            match self.params.ga_mode {
+                GaMode::Generational => self.generation(&mut run, tx, control),
+                GaMode::SteadyState => self.steady_state_window(&mut run, tx, control),
+                GaMode::Island => self.island_generation(&mut run, tx, control),
+            }
+            if run.gen < self.params.max_steps { Progress::Continue } else { Progress::Done }
+        };
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        let Some(run) = &self.run else { return SolverState::default(); };
+        let best = run.islands.iter()
+            .filter_map(|isl| lowest(&isl.population))
+            .min_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal))
+            .cloned();
+        SolverState { step: run.gen, best }
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &mut self.run else { return; };
+        run.pool = None;
+        let label = match self.params.ga_mode {
+            GaMode::Generational => "GA",
+            GaMode::SteadyState => "Steady-State GA",
+            GaMode::Island => "Island GA",
+        };
+        let _ = tx.send(SolverEvent::Log(format!("{} Finished. Total Evals: {}", label, run.total_evals)));
+    }
+
+    fn tune(&mut self, tuning: &Tuning) -> Result<String> {
+        let Some(mut run) = self.run.take() else { bail!("The GA has not started yet"); };
+        let result = self.retune(&mut run, tuning);
+        self.run = Some(run);
+        result
+    }
+}
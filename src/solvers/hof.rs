@@ -0,0 +1,141 @@
+use crate::analysis::pareto;
+use crate::core::domain::{Cluster, Objective};
+
+/// A Hall of Fame entry: a distinct minimum and the walker that found it first.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub cluster: Cluster,
+    pub finder: usize,
+}
+
+/// Lowest distinct minima found by a group of walkers, deduplicated by `hash_key`.
+#[derive(Debug, Clone)]
+pub struct HallOfFame {
+    capacity: usize,
+    entries: Vec<Entry>, // Sorted by energy, lowest first
+}
+
+impl HallOfFame {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), entries: Vec::new() }
+    }
+
+    /// Records a minimum found by `walker`. Returns true if it is a new entry
+    /// (a duplicate with lower energy replaces the stored geometry but is not "new").
+    pub fn insert(&mut self, cluster: &Cluster, walker: usize) -> bool {
+        let Some(energy) = cluster.energy else { return false; };
+
+        if let Some(existing) = self.find_mut(cluster.hash_key.as_deref()) {
+            if energy < existing.cluster.energy.unwrap_or(f64::MAX) {
+                existing.cluster = cluster.clone();
+            }
+            self.sort();
+            return false;
+        }
+
+        if self.entries.len() >= self.capacity
+            && energy >= self.entries.last().and_then(|e| e.cluster.energy).unwrap_or(f64::MAX)
+        {
+            return false;
+        }
+
+        self.entries.push(Entry { cluster: cluster.clone(), finder: walker });
+        self.sort();
+        self.entries.truncate(self.capacity);
+        true
+    }
+
+    /// The walker that first found the isomer with this hash, if it is on record.
+    pub fn finder_of(&self, hash_key: Option<&str>) -> Option<usize> {
+        let key = hash_key?;
+        self.entries.iter().find(|e| e.cluster.hash_key.as_deref() == Some(key)).map(|e| e.finder)
+    }
+
+    pub fn best(&self) -> Option<&Cluster> {
+        self.entries.first().map(|e| &e.cluster)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn find_mut(&mut self, hash_key: Option<&str>) -> Option<&mut Entry> {
+        let key = hash_key?;
+        self.entries.iter_mut().find(|e| e.cluster.hash_key.as_deref() == Some(key))
+    }
+
+    fn sort(&mut self) {
+        self.entries.sort_by(|a, b| {
+            a.cluster.energy.partial_cmp(&b.cluster.energy).unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+}
+
+/// Non-dominated archive for multi-objective runs: the Hall of Fame holds a Pareto front
+/// instead of the lowest energies. Isomers are deduplicated by `hash_key`. When full, the
+/// most crowded member is dropped.
+#[derive(Debug, Clone)]
+pub struct ParetoFront {
+    objectives: Vec<Objective>,
+    capacity: usize,
+    members: Vec<(Vec<f64>, Cluster)>, // Sorted by the first objective
+}
+
+impl ParetoFront {
+    pub fn new(objectives: Vec<Objective>, capacity: usize) -> Self {
+        Self { objectives, capacity: capacity.max(2), members: Vec::new() }
+    }
+
+    /// Minimisation-oriented objective values, or `None` if any is missing.
+    pub fn costs(&self, cluster: &Cluster) -> Option<Vec<f64>> {
+        self.objectives.iter().map(|o| o.cost(cluster)).collect()
+    }
+
+    /// Offers a cluster to the front. Returns true if the front changed.
+    pub fn insert(&mut self, cluster: &Cluster) -> bool {
+        let Some(costs) = self.costs(cluster) else { return false; };
+
+        let twin = cluster.hash_key.as_deref();
+        for (c, member) in &self.members {
+            let same_isomer = twin.is_some() && member.hash_key.as_deref() == twin;
+            if pareto::dominates(c, &costs) || *c == costs || (same_isomer && !pareto::dominates(&costs, c)) {
+                return false;
+            }
+        }
+
+        self.members.retain(|(c, _)| !pareto::dominates(&costs, c));
+        self.members.push((costs, cluster.clone()));
+
+        if self.members.len() > self.capacity {
+            let points: Vec<Vec<f64>> = self.members.iter().map(|(c, _)| c.clone()).collect();
+            let front: Vec<usize> = (0..points.len()).collect();
+            let crowding = pareto::crowding_distance(&points, &front);
+            if let Some(i) = (0..crowding.len()).min_by(|&a, &b| crowding[a].total_cmp(&crowding[b])) {
+                self.members.remove(i);
+            }
+        }
+
+        self.members.sort_by(|a, b| a.0.first().unwrap_or(&0.0).total_cmp(b.0.first().unwrap_or(&0.0)));
+        true
+    }
+
+    pub fn members(&self) -> Vec<Cluster> {
+        self.members.iter().map(|(_, c)| c.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::{anyhow, bail, Result};
+use crossbeam_channel::Sender;
+use nalgebra::Vector3;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::domain::{Cluster, Params, ClusterStatus, EvalFailure};
+use crate::core::spatial;
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::{Evaluator, classify_failure};
+use crate::engine::native::forcefield::{self, MdSettings};
+use crate::engine::native::potentials::PairPotential;
+use crate::analysis::topology;
+use crate::solvers::bh::BasinHopping;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, report_evaluator, seeded_rng};
+
+/// Kinetic energy feedback (Goedecker, J. Chem. Phys. 120, 9911): the escape fell back into
+/// the current minimum, reached a minimum seen before, or reached a new one.
+const BETA_SAME: f64 = 1.05;
+const BETA_OLD: f64 = 1.05;
+const BETA_NEW: f64 = 1.0 / 1.05;
+/// Acceptance threshold feedback after an accepted / rejected hop.
+const ALPHA_ACCEPT: f64 = 1.0 / 1.02;
+const ALPHA_REJECT: f64 = 1.02;
+/// Hard cap on MD steps per escape.
+const MD_MAX_STEPS: usize = 5000;
+
+/// Outcome of one escape attempt.
+#[allow(clippy::large_enum_variant)]
+enum Hop {
+    /// The trajectory relaxed back into the current minimum.
+    Same,
+    /// A different minimum; `true` if it was already in the history.
+    Found(Cluster, bool),
+    Failed(EvalFailure),
+}
+
+/// Feedback state: kinetic energy, acceptance threshold and visit counts per minimum.
+struct Feedback {
+    ekin: f64,
+    ediff: f64,
+    history: HashMap<String, usize>,
+}
+
+impl Feedback {
+    /// Fraction of visits that reached a distinct minimum.
+    fn diversity(&self) -> f64 {
+        let visits: usize = self.history.values().sum();
+        if visits > 0 { self.history.len() as f64 / visits as f64 } else { 0.0 }
+    }
+}
+
+/// Minima Hopping on a single walker.
+///
+/// Each hop gives the atoms random velocities at the current kinetic energy, integrates a
+/// short MD trajectory with the native potential until `md_minima` potential-energy minima
+/// have been crossed, and relaxes the end point with the evaluator. The history of visited
+/// minima feeds back on the kinetic energy; a separate threshold `ediff` decides acceptance.
+pub struct MinimaHopping {
+    hopper: BasinHopping,
+    evaluator: Arc<dyn Evaluator>,
+    potential: Arc<dyn PairPotential>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    start: Option<Cluster>,
+    run: Option<Run>,
+}
+
+/// Walker state between hops.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    feedback: Feedback,
+    current: Cluster,
+    best: Cluster,
+    hop: usize,
+    accepted: usize,
+    start_time: Instant,
+}
+
+impl MinimaHopping {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        potential: Arc<dyn PairPotential>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        let hopper = BasinHopping::new(evaluator.clone(), grid.clone(), params.clone());
+        Self { hopper, evaluator, potential, grid, params, start: None, run: None }
+    }
+
+    /// Starts from `cluster` instead of a random structure.
+    pub fn with_start(mut self, cluster: Cluster) -> Self {
+        self.start = Some(cluster);
+        self
+    }
+
+    pub fn solve(self, current: Cluster, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self.with_start(current), tx);
+    }
+
+    /// One hop: escape -> relax -> feedback -> accept.
+    fn hop(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        if run.hop >= self.params.max_steps { return Progress::Done; }
+        run.hop += 1;
+        let i = run.hop;
+        let feedback = &mut run.feedback;
+
+        match self.escape(&run.current, feedback.ekin, i, &feedback.history, &mut run.rng) {
+            Hop::Same => feedback.ekin *= BETA_SAME,
+            Hop::Failed(kind) => run.failures.record(kind),
+            Hop::Found(candidate, seen) => {
+                feedback.ekin *= if seen { BETA_OLD } else { BETA_NEW };
+                *feedback.history.entry(candidate.hash_key.clone().unwrap_or_default()).or_insert(0) += 1;
+
+                let delta = candidate.energy.unwrap_or(f64::MAX) - run.current.energy.unwrap_or(f64::MAX);
+                if delta < feedback.ediff {
+                    run.current = candidate;
+                    feedback.ediff *= ALPHA_ACCEPT;
+                    run.accepted += 1;
+                    if run.current.energy < run.best.energy {
+                        run.best = run.current.clone();
+                        let _ = tx.send(SolverEvent::NewBest(run.best.clone()));
+                    }
+                } else {
+                    feedback.ediff *= ALPHA_REJECT;
+                }
+            }
+        }
+
+        self.report_hop(tx, i, &run.current, &run.best, &run.feedback, &run.failures);
+        if i < self.params.max_steps { Progress::Continue } else { Progress::Done }
+    }
+
+    /// One MD escape from `current` at kinetic energy `ekin` per atom, followed by relaxation.
+    fn escape(
+        &self,
+        current: &Cluster,
+        ekin: f64,
+        iter: usize,
+        history: &HashMap<String, usize>,
+        rng: &mut impl Rng,
+    ) -> Hop {
+        let mut candidate = current.clone();
+        candidate.origin = format!("MH_{}", iter);
+        candidate.energy = None;
+        candidate.hash_key = None;
+        candidate.status = ClusterStatus::Born;
+        assign_velocities(&mut candidate, ekin, rng);
+
+        let settings = MdSettings {
+            timestep: self.params.md_timestep,
+            minima: self.params.md_minima.max(1),
+            max_steps: MD_MAX_STEPS,
+        };
+        let md = forcefield::molecular_dynamics(self.potential.as_ref(), &mut candidate, &settings);
+        if !md.potential_energy.is_finite() {
+            return Hop::Failed(EvalFailure::GeometricCollapse);
+        }
+        spatial::wrap_or_center(&mut candidate);
+
+        if !spatial::check_overlap(&candidate, &self.grid) {
+            return Hop::Failed(EvalFailure::Overlap);
+        }
+        if let Err(e) = self.hopper.relax(&mut candidate) {
+            return Hop::Failed(classify_failure(&e));
+        }
+
+        let key = topology::generate_hash_key(&candidate, 1.5);
+        if Some(&key) == current.hash_key.as_ref() {
+            return Hop::Same;
+        }
+        let seen = history.contains_key(&key);
+        candidate.hash_key = Some(key);
+        Hop::Found(candidate, seen)
+    }
+
+    /// Maps the walker onto `GenStats`: `diversity` is the fraction of hops that reached a
+    /// distinct minimum and `mutation_rate` the kinetic energy relative to its initial value.
+    fn report_hop(
+        &self,
+        tx: &Sender<SolverEvent>,
+        iter: usize,
+        current: &Cluster,
+        best: &Cluster,
+        feedback: &Feedback,
+        failures: &FailureTally,
+    ) {
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: iter,
+            best_energy: best.energy.unwrap_or(0.0),
+            avg_energy: current.energy.unwrap_or(0.0),
+            worst_energy: current.energy.unwrap_or(0.0),
+            diversity: feedback.diversity(),
+            valid_count: 1,
+            pop_size: 1,
+            mutation_rate: if self.params.md_kinetic_energy > 0.0 { feedback.ekin / self.params.md_kinetic_energy } else { 0.0 },
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        solvers::report_evaluator_every(self.evaluator.as_ref(), tx, iter);
+    }
+}
+
+/// Random velocities with zero net momentum, scaled to `ekin` per free atom (unit masses).
+fn assign_velocities(cluster: &mut Cluster, ekin: f64, rng: &mut impl Rng) {
+    let free = cluster.atoms.iter().filter(|a| !a.is_fixed).count();
+    if free == 0 {
+        return;
+    }
+
+    for atom in &mut cluster.atoms {
+        atom.velocity = if atom.is_fixed {
+            Vector3::zeros()
+        } else {
+            Vector3::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0))
+        };
+    }
+
+    if free > 1 {
+        let drift = cluster.atoms.iter().filter(|a| !a.is_fixed).map(|a| a.velocity).sum::<Vector3<f64>>() / free as f64;
+        for atom in cluster.atoms.iter_mut().filter(|a| !a.is_fixed) {
+            atom.velocity -= drift;
+        }
+    }
+
+    let kinetic: f64 = cluster.atoms.iter().map(|a| 0.5 * a.velocity.norm_squared()).sum();
+    if kinetic > 0.0 {
+        let scale = (ekin * free as f64 / kinetic).sqrt();
+        for atom in &mut cluster.atoms {
+            atom.velocity *= scale;
+        }
+    }
+}
+
+impl Solver for MinimaHopping {
+    fn name(&self) -> &str { "Minima Hopping" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let mut rng = seeded_rng(self.params.seed);
+
+        if self.params.max_steps == 0 {
+            bail!("Max steps set to 0. Exiting.");
+        }
+
+        let mut current = match self.start.take() {
+            Some(c) => c,
+            None => Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, &mut rng)
+                .ok_or_else(|| anyhow!("CRITICAL: Failed to generate a starting structure."))?,
+        };
+
+        // 1. Initial Relaxation
+        if current.energy.is_none() {
+            let _ = tx.send(SolverEvent::Log("Relaxing initial structure...".to_string()));
+            self.hopper.relax(&mut current).map_err(|e| anyhow!("Initial relaxation failed: {}", e))?;
+        }
+        current.hash_key = Some(topology::generate_hash_key(&current, 1.5));
+
+        let feedback = Feedback {
+            ekin: self.params.md_kinetic_energy,
+            ediff: self.params.hop_ediff,
+            history: HashMap::from([(current.hash_key.clone().unwrap_or_default(), 1)]),
+        };
+
+        let best = current.clone();
+        let _ = tx.send(SolverEvent::NewBest(best.clone()));
+
+        self.run = Some(Run {
+            rng,
+            failures: FailureTally::default(),
+            feedback,
+            current,
+            best,
+            hop: 0,
+            accepted: 0,
+            start_time: Instant::now(),
+        });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.hop(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.hop, best: Some(r.best.clone()) })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let rate = if duration > 0.0 { run.hop as f64 / duration } else { 0.0 };
+        let _ = tx.send(SolverEvent::Log(format!(
+            "MH Finished. Acceptance: {}/{}, {} distinct minima, final Ekin {:.3} eV, ediff {:.3} eV",
+            run.accepted, run.hop, run.feedback.history.len(), run.feedback.ekin, run.feedback.ediff
+        )));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
@@ -1,4 +1,49 @@
-use crate::core::domain::Cluster;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use anyhow::{bail, Result};
+use crossbeam_channel::{Receiver, Sender};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::chemistry::InteractionGrid;
+use crate::core::domain::{Cluster, ClusterStatus, EvalFailure};
+use crate::core::spatial;
+use crate::engine::evaluator::{Evaluator, classify_failure};
+use crate::engine::instrument::EvaluatorStats;
+use crate::solvers::stopping::{StopReason, StoppingCriteria};
+
+/// Cumulative count of rejected candidates per failure class.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FailureTally {
+    counts: BTreeMap<EvalFailure, usize>,
+}
+
+impl FailureTally {
+    pub fn record(&mut self, kind: EvalFailure) {
+        *self.counts.entry(kind).or_insert(0) += 1;
+    }
+
+    pub fn count(&self, kind: EvalFailure) -> usize {
+        self.counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Non-zero classes, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (EvalFailure, usize)> + '_ {
+        self.counts.iter().map(|(k, v)| (*k, *v))
+    }
+
+    pub fn merge(&mut self, other: &FailureTally) {
+        for (kind, n) in other.iter() {
+            *self.counts.entry(kind).or_insert(0) += n;
+        }
+    }
+}
 
 /// Detailed statistics for a single generation/step.
 /// Used for telemetry and UI visualization.
@@ -15,6 +60,16 @@ pub struct GenStats {
     /// The current mutation rate being applied.
     /// Allows the UI to visualize adaptive strategies (e.g. Hyper-Mutation spikes).
     pub mutation_rate: f64, 
+
+    /// Current perturbation step size (Å), for solvers that adapt it.
+    pub step_size: Option<f64>,
+    /// Current Metropolis temperature (K), for single-temperature solvers.
+    pub temperature: Option<f64>,
+    /// Recent move acceptance ratio (0 to 1), for Monte Carlo walkers.
+    pub acceptance: Option<f64>,
+
+    /// Cumulative rejected candidates by failure class since the run started.
+    pub failures: FailureTally,
 }
 
 impl Default for GenStats {
@@ -28,12 +83,79 @@ impl Default for GenStats {
             valid_count: 0,
             pop_size: 0,
             mutation_rate: 0.0,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: FailureTally::default(),
         }
     }
 }
 
+/// Telemetry for one replica of a parallel-tempering run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicaStats {
+    pub temperature: f64,
+    /// Energy of the configuration currently held at this temperature.
+    pub energy: f64,
+    /// Accepted / attempted BH moves at this temperature.
+    pub acceptance_rate: f64,
+    /// Accepted / attempted swaps with the next-hotter replica (0 for the hottest).
+    pub swap_rate: f64,
+}
+
+/// Relaxed-energy distribution for one box size of a ScanBox run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxScanStats {
+    pub box_size: f64,
+    /// Structures requested at this box size.
+    pub samples: usize,
+    /// Structures that relaxed successfully.
+    pub valid: usize,
+    pub best_energy: Option<f64>,
+    pub mean_energy: Option<f64>,
+    pub worst_energy: Option<f64>,
+}
+
+impl BoxScanStats {
+    pub fn from_energies(box_size: f64, samples: usize, energies: &[f64]) -> Self {
+        let (best, mean, worst) = if energies.is_empty() {
+            (None, None, None)
+        } else {
+            (
+                Some(energies.iter().cloned().fold(f64::MAX, f64::min)),
+                Some(energies.iter().sum::<f64>() / energies.len() as f64),
+                Some(energies.iter().cloned().fold(f64::MIN, f64::max)),
+            )
+        };
+        Self { box_size, samples, valid: energies.len(), best_energy: best, mean_energy: mean, worst_energy: worst }
+    }
+}
+
+/// One distinct minimum in the energy spectrum of a random search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectrumEntry {
+    pub energy: f64,
+    /// Random starts that relaxed into this minimum.
+    pub hits: usize,
+}
+
+/// Learned selection state of one GA mutation operator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperatorStats {
+    pub name: String,
+    /// Current selection probability.
+    pub probability: f64,
+    /// Smoothed reward estimate (0 to 1).
+    pub quality: f64,
+    /// Evaluated children bred with this operator since the run started.
+    pub uses: usize,
+    /// Of those, children that entered the population or set a new best.
+    pub successes: usize,
+}
+
 /// Events emitted by solvers to the main thread.
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum SolverEvent {
     /// Diagnostic log message.
     Log(String),
@@ -45,12 +167,347 @@ pub enum SolverEvent {
     /// A completed generation or basin-hopping step with full statistics.
     GenerationUpdate(GenStats),
 
+    /// Cumulative evaluator timing and health statistics.
+    /// Only sent when the evaluator collects them (`InstrumentedEvaluator`).
+    EvaluatorStats(EvaluatorStats),
+
+    /// Per-replica acceptance and swap rates (parallel tempering only), coldest first.
+    ReplicaUpdate(Vec<ReplicaStats>),
+
+    /// Energy distribution for every box size scanned so far (ScanBox only), in scan order.
+    BoxScanUpdate(Vec<BoxScanStats>),
+
+    /// Lowest distinct minima found so far with their hit counts (random search only), lowest first.
+    SpectrumUpdate(Vec<SpectrumEntry>),
+
+    /// Learned mutation operator probabilities (GA with adaptive operator selection only).
+    OperatorUpdate(Vec<OperatorStats>),
+
+    /// Current Pareto front, sorted by the first objective (multi-objective GA only).
+    ParetoUpdate(Vec<Cluster>),
+
     /// A structure that beats the current global best (Energy Record).
     NewBest(Cluster),
 
-    /// Solver has finished its run.
-    Finished,
+    /// Current step and best structure, in reply to `SolverCommand::Snapshot`.
+    Snapshot(SolverState),
+
+    /// A `SolverCommand::Tune` the solver accepted (its confirmation is logged separately).
+    Tuned(Tuning),
+
+    /// Solver has finished its run, and why.
+    Finished(StopReason),
+}
+
+/// Builds the solver RNG from `Params.seed`.
+/// A seed of 0 draws fresh entropy, so unseeded runs differ from each other.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    if seed == 0 {
+        ChaCha8Rng::from_entropy()
+    } else {
+        ChaCha8Rng::seed_from_u64(seed)
+    }
+}
+
+/// Forwards the evaluator's statistics to the UI, if it keeps any.
+pub fn report_evaluator(evaluator: &dyn Evaluator, tx: &Sender<SolverEvent>) {
+    if let Some(stats) = evaluator.stats() {
+        let _ = tx.send(SolverEvent::EvaluatorStats(stats));
+    }
+}
+
+/// Steps between evaluator statistics reports for solvers that report every step.
+pub const EVALUATOR_REPORT_INTERVAL: usize = 10;
+
+/// `report_evaluator` on every `EVALUATOR_REPORT_INTERVAL`-th step.
+pub fn report_evaluator_every(evaluator: &dyn Evaluator, tx: &Sender<SolverEvent>, step: usize) {
+    if step.is_multiple_of(EVALUATOR_REPORT_INTERVAL) {
+        report_evaluator(evaluator, tx);
+    }
+}
+
+/// Evaluates `cluster` and adopts the engine's energy, properties and relaxed geometry.
+/// On failure the cluster is marked `Discarded` (energy cleared) and the error returned.
+pub fn relax_in_place(evaluator: &dyn Evaluator, cluster: &mut Cluster) -> Result<()> {
+    let adopted = match evaluator.evaluate(cluster) {
+        Ok(res) => match res.relaxed_cluster {
+            Some(geom) if geom.atoms.len() != cluster.atoms.len() => Err(EvalFailure::AtomCountMismatch.into()),
+            geom => {
+                if let Some(geom) = geom {
+                    for (orig, new) in cluster.atoms.iter_mut().zip(geom.atoms.iter()) {
+                        orig.position = new.position;
+                    }
+                    if geom.lattice.is_some() { cluster.lattice = geom.lattice; }
+                    spatial::wrap_or_center(cluster);
+                }
+                cluster.energy = Some(res.energy);
+                cluster.properties = res.properties;
+                cluster.status = ClusterStatus::Evaluated;
+                Ok(())
+            }
+        },
+        Err(e) => Err(e),
+    };
+    if let Err(e) = &adopted {
+        cluster.status = ClusterStatus::Discarded(classify_failure(e));
+        cluster.energy = None;
+    }
+    adopted
+}
+
+/// Up to `count` random clusters in a box of `box_size`, the `i`-th named `origin(i)`.
+/// Placements that fail are tallied as overlaps.
+pub fn random_batch(
+    atom_counts: &[usize],
+    box_size: f64,
+    grid: &InteractionGrid,
+    count: usize,
+    rng: &mut impl Rng,
+    failures: &mut FailureTally,
+    origin: impl Fn(usize) -> String,
+) -> Vec<Cluster> {
+    (0..count)
+        .filter_map(|i| {
+            let c = Cluster::new_random(atom_counts, box_size, grid, rng);
+            if c.is_none() { failures.record(EvalFailure::Overlap); }
+            c.map(|mut c| { c.origin = origin(i); c })
+        })
+        .collect()
+}
+
+// --- Solver Lifecycle ---
+
+/// Whether a solver has work left after a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    Continue,
+    Done,
+}
+
+/// Snapshot of a running solver.
+#[derive(Debug, Clone, Default)]
+pub struct SolverState {
+    /// Steps completed so far (see `Solver::step`).
+    pub step: usize,
+    /// Lowest-energy structure found so far.
+    pub best: Option<Cluster>,
+}
+
+/// Step-wise lifecycle shared by every search algorithm.
+///
+/// `init` builds the starting population or walker, each `step` advances the search by one
+/// iteration (a generation, a BH move or block, a PT swap interval, a scanned box, ...) and
+/// `finish` sends the closing reports. `run` drives the cycle; `Finished` is sent by the
+/// driver, not by the solver.
+pub trait Solver: Send {
+    fn name(&self) -> &str;
+
+    /// Prepares the run. An error means nothing could be started; its message is logged.
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()>;
+
+    /// Steps with many evaluations may call `control.checkpoint()` between them, and should
+    /// wrap up early once it returns false.
+    fn step(&mut self, tx: &Sender<SolverEvent>, control: &Control) -> Progress;
+
+    fn state(&self) -> SolverState;
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>);
+
+    /// Applies a live change between steps. Returns the confirmation to log.
+    fn tune(&mut self, tuning: &Tuning) -> Result<String> {
+        bail!("{} does not support live changes to the {}", self.name(), tuning.name())
+    }
+}
+
+/// Runs `solver` to completion: init, step until done, finish, then `Finished`.
+pub fn run(solver: &mut dyn Solver, tx: Sender<SolverEvent>) {
+    run_controlled(solver, tx, &Control::detached(), &mut StoppingCriteria::default());
+}
+
+/// `run`, obeying the commands on `control` between steps and ending early once one of
+/// `stopping` is met. A stopped run still finishes. Returns the reason sent with `Finished`.
+pub fn run_controlled(
+    solver: &mut dyn Solver,
+    tx: Sender<SolverEvent>,
+    control: &Control,
+    stopping: &mut StoppingCriteria,
+) -> StopReason {
+    let reason = match solver.init(&tx) {
+        Ok(()) => {
+            stopping.start();
+            let reason = loop {
+                let live = control.checkpoint();
+                for cmd in control.take_pending() {
+                    match cmd {
+                        SolverCommand::Snapshot => {
+                            let _ = tx.send(SolverEvent::Snapshot(solver.state()));
+                        }
+                        SolverCommand::Tune(tuning) => match solver.tune(&tuning) {
+                            Ok(msg) => {
+                                let _ = tx.send(SolverEvent::Log(msg));
+                                let _ = tx.send(SolverEvent::Tuned(tuning));
+                            }
+                            Err(e) => {
+                                let _ = tx.send(SolverEvent::Log(format!("{:#}", e)));
+                            }
+                        },
+                        _ => {}
+                    }
+                }
+                if !live {
+                    let _ = tx.send(SolverEvent::Log(format!("{} stopped at step {}.", solver.name(), solver.state().step)));
+                    break StopReason::Stopped;
+                }
+                if solver.step(&tx, control) == Progress::Done {
+                    break StopReason::Completed;
+                }
+                if stopping.is_active() {
+                    if let Some(reason) = stopping.check(&solver.state()) {
+                        break reason;
+                    }
+                }
+            };
+            solver.finish(&tx);
+            reason
+        }
+        Err(e) => {
+            let _ = tx.send(SolverEvent::Log(format!("{:#}", e)));
+            StopReason::Failed
+        }
+    };
+    let _ = tx.send(SolverEvent::Finished(reason.clone()));
+    reason
+}
+
+// --- Solver Control ---
+
+/// Commands from the UI to a running solver.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverCommand {
+    Pause,
+    Resume,
+    /// Ends the run after the evaluations in progress, with the usual closing reports.
+    Stop,
+    /// Asks for a `SolverEvent::Snapshot`.
+    Snapshot,
+    /// Live change, applied before the next step.
+    Tune(Tuning),
+}
+
+/// Live changes a solver may accept while running.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tuning {
+    /// GA base mutation rate.
+    MutationRate(f64),
+    /// BH walker temperature (K).
+    Temperature(f64),
+    /// BH perturbation step size (Å).
+    StepSize(f64),
+    /// Immediate GA mass extinction.
+    Extinction,
+}
+
+impl Tuning {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tuning::MutationRate(_) => "mutation rate",
+            Tuning::Temperature(_) => "temperature",
+            Tuning::StepSize(_) => "step size",
+            Tuning::Extinction => "mass extinction",
+        }
+    }
+}
+
+/// How often a paused checkpoint looks at the flags again (another thread may take the resume).
+const PAUSE_POLL: Duration = Duration::from_millis(50);
+
+/// Solver-thread end of the command channel.
+///
+/// Pause, resume and stop take effect in whichever thread calls `checkpoint` first: the
+/// runner between steps, GA and BH also between evaluations. Other commands are queued
+/// for the runner. Dropping the sending end counts as a stop.
+pub struct Control {
+    commands: Receiver<SolverCommand>,
+    paused: AtomicBool,
+    stopped: AtomicBool,
+    pending: Mutex<VecDeque<SolverCommand>>,
+}
+
+impl Control {
+    pub fn new(commands: Receiver<SolverCommand>) -> Self {
+        Self {
+            commands,
+            paused: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+            pending: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// A control no command can reach.
+    pub fn detached() -> Self {
+        Self::new(crossbeam_channel::never())
+    }
+
+    /// Applies queued commands and blocks while paused. Returns false once a stop was requested.
+    pub fn checkpoint(&self) -> bool {
+        loop {
+            let waiting = self.paused() && !self.stopped();
+            let next = if waiting {
+                self.commands.recv_timeout(PAUSE_POLL).map_err(|e| e.is_disconnected())
+            } else {
+                self.commands.try_recv().map_err(|e| e.is_disconnected())
+            };
+            match next {
+                Ok(cmd) => self.apply(cmd),
+                Err(true) => {
+                    self.stopped.store(true, Ordering::Relaxed);
+                    break;
+                }
+                Err(false) if waiting => continue,
+                Err(false) => break,
+            }
+        }
+        !self.stopped()
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub fn stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Commands other than pause, resume and stop, oldest first.
+    pub fn take_pending(&self) -> Vec<SolverCommand> {
+        self.pending.lock().map(|mut q| q.drain(..).collect()).unwrap_or_default()
+    }
+
+    fn apply(&self, cmd: SolverCommand) {
+        match cmd {
+            SolverCommand::Pause => self.paused.store(true, Ordering::Relaxed),
+            SolverCommand::Resume => self.paused.store(false, Ordering::Relaxed),
+            SolverCommand::Stop => self.stopped.store(true, Ordering::Relaxed),
+            other => {
+                if let Ok(mut q) = self.pending.lock() {
+                    q.push_back(other);
+                }
+            }
+        }
+    }
 }
 
+pub mod acceptance;
+pub mod aos;
 pub mod bh;
-pub mod ga;
\ No newline at end of file
+pub mod ga;
+pub mod hof;
+pub mod mh;
+pub mod pso;
+pub mod pt;
+pub mod random;
+pub mod registry;
+pub mod sa;
+pub mod scan;
+pub mod ss;
+pub mod stopping;
\ No newline at end of file
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::{anyhow, Result};
+use crossbeam_channel::Sender;
+use nalgebra::Vector3;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::core::domain::{Cluster, Params, ClusterStatus, EvalFailure};
+use crate::core::spatial;
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::Evaluator;
+use crate::analysis::{alignment, topology};
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, report_evaluator, seeded_rng};
+
+/// One swarm member. Velocities live in `position.atoms[i].velocity`.
+struct Particle {
+    position: Cluster,
+    personal_best: Cluster,
+}
+
+/// Particle Swarm Optimization over cluster geometries.
+///
+/// Each particle is a relaxed cluster carrying per-atom velocities. Before every update the
+/// personal and global bests are re-labelled onto the particle by optimal atom assignment,
+/// so the pull terms compare equivalent atoms rather than arbitrary indices.
+pub struct ParticleSwarm {
+    evaluator: Arc<dyn Evaluator>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    run: Option<Run>,
+}
+
+/// Swarm state between iterations.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    swarm: Vec<Particle>,
+    global_best: Cluster,
+    iter: usize,
+    start_time: Instant,
+}
+
+impl ParticleSwarm {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        Self { evaluator, grid, params, run: None }
+    }
+
+    pub fn solve(mut self, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self, tx);
+    }
+
+    /// One swarm update: fly, relax, then refresh the personal and global bests.
+    fn iterate(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        if run.iter >= self.params.max_steps { return Progress::Done; }
+        run.iter += 1;
+        let iter = run.iter;
+
+        let previous: Vec<Cluster> = run.swarm.iter().map(|p| p.position.clone()).collect();
+        let mut moved: Vec<Cluster> = run.swarm.iter()
+            .map(|p| self.fly(p, &run.global_best, &mut run.rng))
+            .collect();
+        for (i, c) in moved.iter_mut().enumerate() {
+            c.origin = format!("PSO_{}_{}", iter, i);
+        }
+
+        // Overlapping moves are rejected before they reach the evaluator
+        for c in &mut moved {
+            if !spatial::check_overlap(c, &self.grid) {
+                c.status = ClusterStatus::Discarded(EvalFailure::Overlap);
+            }
+        }
+        self.evaluate_swarm(&mut moved, &mut run.failures);
+
+        for ((particle, mut next), prev) in run.swarm.iter_mut().zip(moved).zip(previous) {
+            if next.energy.is_none() {
+                // Failed move: stay put and stop, so the particle doesn't keep pushing into the wall
+                particle.position = prev;
+                for atom in &mut particle.position.atoms {
+                    atom.velocity = Vector3::zeros();
+                }
+                continue;
+            }
+
+            next.hash_key = Some(topology::generate_hash_key(&next, 1.5));
+            if next.energy < particle.personal_best.energy {
+                particle.personal_best = next.clone();
+            }
+            if next.energy < run.global_best.energy {
+                run.global_best = next.clone();
+                let _ = tx.send(SolverEvent::NewBest(run.global_best.clone()));
+            }
+            particle.position = next;
+        }
+
+        self.report_iteration(tx, iter, &run.swarm, &run.global_best, &run.failures);
+        if run.iter < self.params.max_steps { Progress::Continue } else { Progress::Done }
+    }
+
+    /// Velocity and position update for one particle:
+    /// `v = w v + c1 r1 (pbest - x) + c2 r2 (gbest - x)`, clamped to `max_velocity`.
+    fn fly(&self, particle: &Particle, global_best: &Cluster, rng: &mut impl Rng) -> Cluster {
+        let x = &particle.position;
+        let mut next = x.clone();
+        next.status = ClusterStatus::Born;
+        next.energy = None;
+        next.hash_key = None;
+
+        let personal = alignment::aligned_positions(x, &particle.personal_best);
+        let social = alignment::aligned_positions(x, global_best);
+        let w = self.params.inertia;
+        let (c1, c2) = (self.params.cognitive_weight, self.params.social_weight);
+
+        for (i, atom) in next.atoms.iter_mut().enumerate() {
+            if atom.is_fixed { continue; }
+            let mut v = atom.velocity * w;
+            if let Some(p) = &personal {
+                v += (p[i] - atom.position) * (c1 * rng.gen::<f64>());
+            }
+            if let Some(g) = &social {
+                v += (g[i] - atom.position) * (c2 * rng.gen::<f64>());
+            }
+            let speed = v.norm();
+            if speed > self.params.max_velocity {
+                v *= self.params.max_velocity / speed;
+            }
+            atom.velocity = v;
+            atom.position += v;
+        }
+        next
+    }
+
+    /// Relaxes every `Born` particle in parallel. Velocities survive relaxation.
+    fn evaluate_swarm(&self, swarm: &mut [Cluster], failures: &mut FailureTally) {
+        swarm.par_iter_mut()
+            .filter(|c| c.status == ClusterStatus::Born)
+            .for_each(|cluster| { let _ = solvers::relax_in_place(self.evaluator.as_ref(), cluster); });
+
+        for c in swarm.iter() {
+            if let ClusterStatus::Discarded(kind) = c.status {
+                failures.record(kind);
+            }
+        }
+    }
+
+    /// Maps the swarm onto `GenStats`: `diversity` is the fraction of distinct isomers
+    /// and `mutation_rate` the mean atom speed relative to `max_velocity`.
+    fn report_iteration(
+        &self,
+        tx: &Sender<SolverEvent>,
+        iter: usize,
+        swarm: &[Particle],
+        global_best: &Cluster,
+        failures: &FailureTally,
+    ) {
+        let energies: Vec<f64> = swarm.iter().filter_map(|p| p.position.energy).collect();
+        let (avg, worst) = if energies.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (energies.iter().sum::<f64>() / energies.len() as f64, energies.iter().cloned().fold(f64::MIN, f64::max))
+        };
+
+        let unique: HashSet<&str> = swarm.iter().filter_map(|p| p.position.hash_key.as_deref()).collect();
+        let diversity = if swarm.is_empty() { 0.0 } else { unique.len() as f64 / swarm.len() as f64 };
+
+        let speeds: Vec<f64> = swarm.iter().flat_map(|p| p.position.atoms.iter().map(|a| a.velocity.norm())).collect();
+        let mean_speed = if speeds.is_empty() { 0.0 } else { speeds.iter().sum::<f64>() / speeds.len() as f64 };
+        let relative_speed = if self.params.max_velocity > 0.0 { mean_speed / self.params.max_velocity } else { 0.0 };
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: iter,
+            best_energy: global_best.energy.unwrap_or(0.0),
+            avg_energy: avg,
+            worst_energy: worst,
+            diversity,
+            valid_count: energies.len(),
+            pop_size: swarm.len(),
+            mutation_rate: relative_speed,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
+
+fn random_vector(rng: &mut impl Rng, max_norm: f64) -> Vector3<f64> {
+    if max_norm <= 0.0 { return Vector3::zeros(); }
+    Vector3::new(
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+    ) * (max_norm / 3f64.sqrt())
+}
+
+impl Solver for ParticleSwarm {
+    fn name(&self) -> &str { "Particle Swarm" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let mut rng = seeded_rng(self.params.seed);
+        let mut failures = FailureTally::default();
+        let start_time = Instant::now();
+
+        // 1. Initial Swarm
+        let _ = tx.send(SolverEvent::Log("Initializing Swarm...".to_string()));
+        let mut initial: Vec<Cluster> = (0..self.params.population_size)
+            .filter_map(|_| Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, &mut rng))
+            .collect();
+        for c in &mut initial {
+            for atom in &mut c.atoms {
+                atom.velocity = random_vector(&mut rng, self.params.max_velocity);
+            }
+        }
+        self.evaluate_swarm(&mut initial, &mut failures);
+        for c in initial.iter_mut().filter(|c| c.energy.is_some()) {
+            c.hash_key = Some(topology::generate_hash_key(c, 1.5));
+        }
+
+        let swarm: Vec<Particle> = initial.into_iter()
+            .filter(|c| c.energy.is_some())
+            .map(|c| Particle { personal_best: c.clone(), position: c })
+            .collect();
+
+        let global_best = swarm.iter()
+            .map(|p| &p.position)
+            .min_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal))
+            .cloned()
+            .ok_or_else(|| anyhow!("CRITICAL: Failed to generate valid initial swarm."))?;
+        let _ = tx.send(SolverEvent::NewBest(global_best.clone()));
+        self.report_iteration(tx, 0, &swarm, &global_best, &failures);
+
+        self.run = Some(Run { rng, failures, swarm, global_best, iter: 0, start_time });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.iterate(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.iter, best: Some(r.global_best.clone()) })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let evals = run.iter * run.swarm.len();
+        let rate = if duration > 0.0 { evals as f64 / duration } else { 0.0 };
+        let _ = tx.send(SolverEvent::Log(format!("PSO Finished. Best: {:.5} eV", run.global_best.energy.unwrap_or(0.0))));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::{anyhow, bail, Result};
+use crossbeam_channel::Sender;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::core::domain::{Cluster, Params};
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::Evaluator;
+use crate::analysis::topology;
+use crate::solvers::acceptance::Metropolis;
+use crate::solvers::bh::{BasinHopping, Move, KB_EV};
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, ReplicaStats, report_evaluator, seeded_rng};
+
+/// One basin-hopping walker pinned to a rung of the temperature ladder.
+/// Configurations move between rungs; temperatures do not.
+struct Replica {
+    temperature: f64,
+    walker: Cluster,
+    best: Cluster,
+    rng: ChaCha8Rng,
+    moves: usize,
+    accepted: usize,
+    swap_attempts: usize,
+    swaps: usize,
+    failures: FailureTally,
+}
+
+impl Replica {
+    fn stats(&self) -> ReplicaStats {
+        ReplicaStats {
+            temperature: self.temperature,
+            energy: self.walker.energy.unwrap_or(0.0),
+            acceptance_rate: if self.moves > 0 { self.accepted as f64 / self.moves as f64 } else { 0.0 },
+            swap_rate: if self.swap_attempts > 0 { self.swaps as f64 / self.swap_attempts as f64 } else { 0.0 },
+        }
+    }
+}
+
+/// Geometric temperature ladder from `params.temperature` to `params.max_temperature`
+/// with `params.replicas` rungs, coldest first.
+pub fn temperature_ladder(params: &Params) -> Vec<f64> {
+    let m = params.replicas.max(1);
+    let t_min = params.temperature.max(1e-6);
+    let t_max = params.max_temperature.max(t_min);
+    if m == 1 {
+        return vec![t_min];
+    }
+    let ratio = (t_max / t_min).powf(1.0 / (m - 1) as f64);
+    (0..m).map(|k| t_min * ratio.powi(k as i32)).collect()
+}
+
+/// Replica-exchange Basin Hopping.
+///
+/// `replicas` BH walkers run in parallel (rayon), one per ladder temperature. Every
+/// `swap_interval` steps neighbouring rungs attempt to exchange configurations, accepted with
+/// probability `min(1, exp[(β_i - β_j)(E_i - E_j)])`. Even and odd pairs alternate.
+pub struct ParallelTempering {
+    hopper: BasinHopping,
+    evaluator: Arc<dyn Evaluator>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    start: Option<Cluster>,
+    run: Option<Run>,
+}
+
+/// Ladder state between exchange attempts.
+struct Run {
+    rng: ChaCha8Rng,
+    replicas: Vec<Replica>,
+    best: Cluster,
+    done: usize,
+    sweep: usize,
+    start_time: Instant,
+}
+
+impl ParallelTempering {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        let hopper = BasinHopping::new(evaluator.clone(), grid.clone(), params.clone());
+        Self { hopper, evaluator, grid, params, start: None, run: None }
+    }
+
+    /// Starts every replica from `cluster` instead of a random structure.
+    pub fn with_start(mut self, cluster: Cluster) -> Self {
+        self.start = Some(cluster);
+        self
+    }
+
+    /// Runs `max_steps` BH steps per replica, all starting from `start`.
+    pub fn solve(self, start: Cluster, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self.with_start(start), tx);
+    }
+
+    /// One parallel BH block of `swap_interval` steps, followed by an exchange attempt.
+    fn sweep(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        if run.done >= self.params.max_steps { return Progress::Done; }
+        let interval = self.params.swap_interval.max(1);
+        let block = interval.min(self.params.max_steps - run.done);
+        let done = run.done;
+
+        run.replicas.par_iter_mut().for_each(|r| {
+            for k in 1..=block {
+                r.moves += 1;
+                match self.hopper.step(&mut r.walker, r.temperature, self.params.step_size, done + k, &mut r.rng, &mut Metropolis) {
+                    Move::Accepted => {
+                        r.accepted += 1;
+                        if r.walker.energy < r.best.energy {
+                            r.best = r.walker.clone();
+                        }
+                    }
+                    Move::Rejected => {}
+                    Move::Failed(kind) => r.failures.record(kind),
+                }
+            }
+        });
+        run.done += block;
+
+        self.exchange(&mut run.replicas, run.sweep % 2, &mut run.rng);
+        run.sweep += 1;
+
+        // Global best across all replicas
+        if let Some(r) = run.replicas.iter().min_by(|a, b| a.best.energy.partial_cmp(&b.best.energy).unwrap_or(std::cmp::Ordering::Equal)) {
+            if r.best.energy < run.best.energy {
+                run.best = r.best.clone();
+                let _ = tx.send(SolverEvent::NewBest(run.best.clone()));
+            }
+        }
+
+        self.report_sweep(tx, run.done, &run.replicas, &run.best);
+        if run.done < self.params.max_steps { Progress::Continue } else { Progress::Done }
+    }
+
+    /// Attempts exchanges between rungs `(i, i + 1)` for every `i` of the given parity.
+    fn exchange(&self, replicas: &mut [Replica], parity: usize, rng: &mut impl Rng) {
+        let mut i = parity;
+        while i + 1 < replicas.len() {
+            let (lo, hi) = replicas.split_at_mut(i + 1);
+            let (cold, hot) = (&mut lo[i], &mut hi[0]);
+
+            cold.swap_attempts += 1;
+            if let (Some(e_cold), Some(e_hot)) = (cold.walker.energy, hot.walker.energy) {
+                let beta_cold = 1.0 / (KB_EV * cold.temperature);
+                let beta_hot = 1.0 / (KB_EV * hot.temperature);
+                let log_p = (beta_cold - beta_hot) * (e_cold - e_hot);
+                if log_p >= 0.0 || rng.gen::<f64>() < log_p.exp() {
+                    std::mem::swap(&mut cold.walker, &mut hot.walker);
+                    cold.swaps += 1;
+                }
+            }
+            i += 2;
+        }
+    }
+
+    /// Maps the ladder onto `GenStats` (`diversity` = distinct isomers among walkers)
+    /// and sends the per-replica telemetry.
+    fn report_sweep(&self, tx: &Sender<SolverEvent>, step: usize, replicas: &[Replica], best: &Cluster) {
+        let energies: Vec<f64> = replicas.iter().filter_map(|r| r.walker.energy).collect();
+        let (avg, worst) = if energies.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (energies.iter().sum::<f64>() / energies.len() as f64, energies.iter().cloned().fold(f64::MIN, f64::max))
+        };
+        let unique: HashSet<String> = replicas.iter()
+            .map(|r| topology::generate_hash_key(&r.walker, 1.5))
+            .collect();
+
+        let mut failures = FailureTally::default();
+        for r in replicas {
+            failures.merge(&r.failures);
+        }
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: step,
+            best_energy: best.energy.unwrap_or(0.0),
+            avg_energy: avg,
+            worst_energy: worst,
+            diversity: unique.len() as f64 / replicas.len().max(1) as f64,
+            valid_count: energies.len(),
+            pop_size: replicas.len(),
+            mutation_rate: 0.0,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures,
+        }));
+        let _ = tx.send(SolverEvent::ReplicaUpdate(replicas.iter().map(Replica::stats).collect()));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
+
+impl Solver for ParallelTempering {
+    fn name(&self) -> &str { "Parallel Tempering" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let mut rng = seeded_rng(self.params.seed);
+
+        if self.params.max_steps == 0 {
+            bail!("Max steps set to 0. Exiting.");
+        }
+
+        let mut start = match self.start.take() {
+            Some(c) => c,
+            None => Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, &mut rng)
+                .ok_or_else(|| anyhow!("CRITICAL: Failed to generate a starting structure."))?,
+        };
+
+        // 1. Initial Relaxation (shared by all replicas)
+        if start.energy.is_none() {
+            let _ = tx.send(SolverEvent::Log("Relaxing initial structure...".to_string()));
+            self.hopper.relax(&mut start).map_err(|e| anyhow!("Initial relaxation failed: {}", e))?;
+        }
+
+        let ladder = temperature_ladder(&self.params);
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Parallel tempering: {} replicas, {:.0} K .. {:.0} K",
+            ladder.len(), ladder[0], ladder[ladder.len() - 1]
+        )));
+
+        let replicas: Vec<Replica> = ladder.iter()
+            .map(|&temperature| Replica {
+                temperature,
+                walker: start.clone(),
+                best: start.clone(),
+                rng: ChaCha8Rng::seed_from_u64(rng.gen()),
+                moves: 0,
+                accepted: 0,
+                swap_attempts: 0,
+                swaps: 0,
+                failures: FailureTally::default(),
+            })
+            .collect();
+
+        let _ = tx.send(SolverEvent::NewBest(start.clone()));
+        self.run = Some(Run { rng, replicas, best: start, done: 0, sweep: 0, start_time: Instant::now() });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.sweep(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.done, best: Some(r.best.clone()) })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let evals = run.done * run.replicas.len();
+        let rate = if duration > 0.0 { evals as f64 / duration } else { 0.0 };
+        let swaps: usize = run.replicas.iter().map(|r| r.swaps).sum();
+        let _ = tx.send(SolverEvent::Log(format!("PT Finished. {} swaps accepted, best {:.5} eV", swaps, run.best.energy.unwrap_or(0.0))));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::Result;
+use crossbeam_channel::Sender;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::core::domain::{Cluster, Params, ClusterStatus};
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::Evaluator;
+use crate::analysis::topology;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, SpectrumEntry, report_evaluator, seeded_rng};
+
+/// Lowest distinct minima sent to the UI in each spectrum update.
+const SPECTRUM_CAPACITY: usize = 50;
+
+/// One distinct relaxed minimum and how often random starts fell into it.
+struct Minimum {
+    cluster: Cluster,
+    hits: usize,
+}
+
+/// AIRSS-style random structure search, as a baseline for the other solvers.
+///
+/// Every round `population_size` fresh random clusters are relaxed in parallel and deduplicated
+/// by topology hash against everything found so far. Telemetry mirrors the GA: a round is a
+/// generation, the lowest `population_size` distinct minima play the part of the population,
+/// and `diversity` is the fraction of the round's valid structures that were distinct.
+pub struct RandomSearch {
+    evaluator: Arc<dyn Evaluator>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    run: Option<Run>,
+}
+
+/// Search state between rounds.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    minima: HashMap<String, Minimum>,
+    best: Option<Cluster>,
+    total_evals: usize,
+    /// Next round to run (round 0 plays the part of the GA's initial population).
+    gen: usize,
+}
+
+impl RandomSearch {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        Self { evaluator, grid, params, run: None }
+    }
+
+    pub fn solve(mut self, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self, tx);
+    }
+
+    /// One round: relax `population_size` random structures and merge them into the spectrum.
+    fn round(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        let gen = run.gen;
+        let gen_start = Instant::now();
+
+        // 1. Random structures
+        let mut batch = solvers::random_batch(
+            &self.params.atom_counts, self.params.box_size, &self.grid, self.params.population_size,
+            &mut run.rng, &mut run.failures, |i| format!("Random_{}_{}", gen, i),
+        );
+        for c in &mut batch {
+            c.generation = gen as u64;
+        }
+
+        // 2. Parallel relaxation
+        batch.par_iter_mut().for_each(|c| { let _ = solvers::relax_in_place(self.evaluator.as_ref(), c); });
+        run.total_evals += batch.len();
+
+        // 3. Deduplicate against every minimum found so far
+        let mut valid = 0;
+        let mut distinct = HashSet::new();
+        for mut c in batch {
+            if let ClusterStatus::Discarded(kind) = c.status {
+                run.failures.record(kind);
+                continue;
+            }
+            let Some(energy) = c.energy else { continue; };
+            valid += 1;
+
+            let key = topology::generate_hash_key(&c, 1.5);
+            distinct.insert(key.clone());
+            c.hash_key = Some(key.clone());
+
+            if energy < run.best.as_ref().and_then(|b| b.energy).unwrap_or(f64::MAX) {
+                run.best = Some(c.clone());
+                let _ = tx.send(SolverEvent::NewBest(c.clone()));
+            }
+            match run.minima.get_mut(&key) {
+                Some(m) => {
+                    m.hits += 1;
+                    if energy < m.cluster.energy.unwrap_or(f64::MAX) {
+                        m.cluster = c;
+                    }
+                }
+                None => {
+                    run.minima.insert(key, Minimum { cluster: c, hits: 1 });
+                }
+            }
+        }
+
+        // 4. Telemetry
+        let mut spectrum = ranked(&run.minima);
+        let diversity = if valid > 0 { distinct.len() as f64 / valid as f64 } else { 0.0 };
+        self.report_round(tx, gen, &spectrum, diversity, &run.failures);
+        spectrum.truncate(SPECTRUM_CAPACITY);
+        let _ = tx.send(SolverEvent::SpectrumUpdate(spectrum));
+
+        let duration = gen_start.elapsed().as_secs_f64();
+        if duration > 0.0 {
+            let _ = tx.send(SolverEvent::WorkerHeartbeat(valid as f64 / duration));
+        }
+
+        run.gen += 1;
+        if run.gen > self.params.max_steps { Progress::Done } else { Progress::Continue }
+    }
+
+    /// Maps a round onto `GenStats`; `ranked` holds every distinct minimum, lowest first.
+    fn report_round(
+        &self,
+        tx: &Sender<SolverEvent>,
+        gen: usize,
+        ranked: &[SpectrumEntry],
+        diversity: f64,
+        failures: &FailureTally,
+    ) {
+        let population = &ranked[..ranked.len().min(self.params.population_size)];
+        let (best, worst, avg) = match (population.first(), population.last()) {
+            (Some(b), Some(w)) => (b.energy, w.energy, population.iter().map(|m| m.energy).sum::<f64>() / population.len() as f64),
+            _ => (0.0, 0.0, 0.0),
+        };
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: gen,
+            best_energy: best,
+            avg_energy: avg,
+            worst_energy: worst,
+            diversity,
+            valid_count: population.len(),
+            pop_size: self.params.population_size,
+            mutation_rate: 0.0,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
+
+impl Solver for RandomSearch {
+    fn name(&self) -> &str { "Random Search" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let _ = tx.send(SolverEvent::Log("Starting random structure search...".to_string()));
+        self.run = Some(Run {
+            rng: seeded_rng(self.params.seed),
+            failures: FailureTally::default(),
+            minima: HashMap::new(),
+            best: None,
+            total_evals: 0,
+            gen: 0,
+        });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.round(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.gen, best: r.best.clone() })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Random search finished. Total Evals: {}, {} distinct minima",
+            run.total_evals, run.minima.len()
+        )));
+    }
+}
+
+/// Every distinct minimum found so far, lowest first.
+fn ranked(minima: &HashMap<String, Minimum>) -> Vec<SpectrumEntry> {
+    let mut levels: Vec<SpectrumEntry> = minima.values()
+        .map(|m| SpectrumEntry { energy: m.cluster.energy.unwrap_or(0.0), hits: m.hits })
+        .collect();
+    levels.sort_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal));
+    levels
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use anyhow::{anyhow, Context, Result};
+
+use crate::core::chemistry::InteractionGrid;
+use crate::core::domain::{AlgorithmType, Cluster, Params};
+use crate::engine::evaluator::Evaluator;
+use crate::engine::native::potentials::PairPotential;
+use crate::solvers::Solver;
+use crate::solvers::bh::BasinHopping;
+use crate::solvers::ga::GeneticAlgorithm;
+use crate::solvers::mh::MinimaHopping;
+use crate::solvers::pso::ParticleSwarm;
+use crate::solvers::pt::ParallelTempering;
+use crate::solvers::random::RandomSearch;
+use crate::solvers::sa::SimulatedAnnealing;
+use crate::solvers::scan::ScanBox;
+use crate::solvers::ss::{SolidSolution, Sublattice};
+
+/// Everything a solver may be built from. The optional inputs are only read by the
+/// solvers noted on each field.
+#[derive(Clone)]
+pub struct SolverContext {
+    pub evaluator: Arc<dyn Evaluator>,
+    pub grid: Arc<InteractionGrid>,
+    pub params: Params,
+    /// Starting structure for the walker-based solvers (BH, SA, PT, MH); random if `None`.
+    pub start: Option<Cluster>,
+    /// SA: cheaper model for the annealing moves. The final best is refined with `evaluator`.
+    pub screening: Option<Arc<dyn Evaluator>>,
+    /// MH: potential integrated by the MD escapes (required).
+    pub potential: Option<Arc<dyn PairPotential>>,
+    /// Solid solution: periodic host and its substitutable sites (required).
+    pub host: Option<(Cluster, Sublattice)>,
+}
+
+impl SolverContext {
+    pub fn new(evaluator: Arc<dyn Evaluator>, grid: Arc<InteractionGrid>, params: Params) -> Self {
+        Self { evaluator, grid, params, start: None, screening: None, potential: None, host: None }
+    }
+}
+
+/// A named solver constructor.
+#[derive(Clone)]
+pub struct SolverEntry {
+    /// Short name accepted by `--algo`.
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub algorithm: AlgorithmType,
+    pub build: fn(SolverContext) -> Result<Box<dyn Solver>>,
+}
+
+/// Lookup table from algorithm names and `AlgorithmType`s to solver constructors.
+#[derive(Clone, Default)]
+pub struct SolverRegistry {
+    entries: Vec<SolverEntry>,
+}
+
+impl SolverRegistry {
+    /// Every solver shipped with the crate.
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.register(SolverEntry { name: "ga", aliases: &["genetic"], algorithm: AlgorithmType::GeneticAlgorithm, build: build_ga });
+        registry.register(SolverEntry { name: "bh", aliases: &["basin-hopping"], algorithm: AlgorithmType::BasinHopping, build: build_bh });
+        registry.register(SolverEntry { name: "sa", aliases: &["annealing"], algorithm: AlgorithmType::SimulatedAnnealing, build: build_sa });
+        registry.register(SolverEntry { name: "pso", aliases: &["swarm"], algorithm: AlgorithmType::ParticleSwarm, build: build_pso });
+        registry.register(SolverEntry { name: "pt", aliases: &["tempering"], algorithm: AlgorithmType::ParallelTempering, build: build_pt });
+        registry.register(SolverEntry { name: "mh", aliases: &["minima-hopping"], algorithm: AlgorithmType::MinimaHopping, build: build_mh });
+        registry.register(SolverEntry { name: "random", aliases: &[], algorithm: AlgorithmType::RandomSearch, build: build_random });
+        registry.register(SolverEntry { name: "scan", aliases: &["scanbox"], algorithm: AlgorithmType::ScanBox, build: build_scan });
+        registry.register(SolverEntry { name: "ss", aliases: &["solid-solution"], algorithm: AlgorithmType::SolidSolution, build: build_ss });
+        registry
+    }
+
+    /// Adds `entry`, replacing any entry for the same algorithm.
+    pub fn register(&mut self, entry: SolverEntry) {
+        self.entries.retain(|e| e.algorithm != entry.algorithm);
+        self.entries.push(entry);
+    }
+
+    /// Case-insensitive lookup by name or alias.
+    pub fn find(&self, name: &str) -> Option<&SolverEntry> {
+        let name = name.to_lowercase();
+        self.entries.iter().find(|e| e.name == name || e.aliases.contains(&name.as_str()))
+    }
+
+    /// Like `find`, but an unknown name is an error listing every registered name and alias.
+    pub fn lookup(&self, name: &str) -> Result<&SolverEntry> {
+        self.find(name).ok_or_else(|| {
+            let known: Vec<String> = self.entries.iter()
+                .map(|e| std::iter::once(e.name).chain(e.aliases.iter().copied()).collect::<Vec<_>>().join("/"))
+                .collect();
+            anyhow!("Unknown algorithm '{}' (expected one of: {})", name, known.join(", "))
+        })
+    }
+
+    pub fn for_algorithm(&self, algorithm: AlgorithmType) -> Option<&SolverEntry> {
+        self.entries.iter().find(|e| e.algorithm == algorithm)
+    }
+
+    /// Builds the solver for `ctx.params.algorithm`.
+    pub fn build(&self, ctx: SolverContext) -> Result<Box<dyn Solver>> {
+        let entry = self.for_algorithm(ctx.params.algorithm)
+            .ok_or_else(|| anyhow!("No solver registered for {:?}", ctx.params.algorithm))?;
+        (entry.build)(ctx)
+    }
+
+    pub fn entries(&self) -> &[SolverEntry] {
+        &self.entries
+    }
+}
+
+// --- Builtin Constructors ---
+
+fn build_ga(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(GeneticAlgorithm::new(ctx.evaluator, ctx.grid, ctx.params)))
+}
+
+fn build_bh(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    let solver = BasinHopping::new(ctx.evaluator, ctx.grid, ctx.params);
+    Ok(Box::new(match ctx.start {
+        Some(c) => solver.with_start(c),
+        None => solver,
+    }))
+}
+
+fn build_sa(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    let solver = match ctx.screening {
+        Some(model) => SimulatedAnnealing::new(model, ctx.grid, ctx.params).with_refinement(ctx.evaluator),
+        None => SimulatedAnnealing::new(ctx.evaluator, ctx.grid, ctx.params),
+    };
+    Ok(Box::new(match ctx.start {
+        Some(c) => solver.with_start(c),
+        None => solver,
+    }))
+}
+
+fn build_pso(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(ParticleSwarm::new(ctx.evaluator, ctx.grid, ctx.params)))
+}
+
+fn build_pt(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    let solver = ParallelTempering::new(ctx.evaluator, ctx.grid, ctx.params);
+    Ok(Box::new(match ctx.start {
+        Some(c) => solver.with_start(c),
+        None => solver,
+    }))
+}
+
+fn build_mh(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    let potential = ctx.potential.ok_or_else(|| anyhow!("Minima hopping needs a pair potential for its MD escapes"))?;
+    let solver = MinimaHopping::new(ctx.evaluator, potential, ctx.grid, ctx.params);
+    Ok(Box::new(match ctx.start {
+        Some(c) => solver.with_start(c),
+        None => solver,
+    }))
+}
+
+fn build_random(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(RandomSearch::new(ctx.evaluator, ctx.grid, ctx.params)))
+}
+
+fn build_scan(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(ScanBox::new(ctx.evaluator, ctx.grid, ctx.params)))
+}
+
+fn build_ss(ctx: SolverContext) -> Result<Box<dyn Solver>> {
+    let (host, sites) = ctx.host.ok_or_else(|| anyhow!("Solid solution setup failed: no host lattice given"))?;
+    let solver = SolidSolution::new(ctx.evaluator, ctx.grid, host, sites, ctx.params)
+        .context("Solid solution setup failed")?;
+    Ok(Box::new(solver))
+}
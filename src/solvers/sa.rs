@@ -0,0 +1,329 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::{anyhow, bail};
+use crossbeam_channel::Sender;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::domain::{Cluster, Params, CoolingSchedule, EvalFailure};
+use crate::engine::evaluator::{Evaluator, classify_failure};
+use crate::engine::operators::Mutator;
+use crate::core::spatial;
+use crate::core::chemistry::InteractionGrid;
+use crate::solvers::acceptance::metropolis;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, report_evaluator, seeded_rng};
+
+/// Moves used for the rolling acceptance ratio.
+const ACCEPTANCE_WINDOW: usize = 50;
+/// Adaptive schedule: steps between temperature corrections.
+const ADAPT_INTERVAL: usize = 20;
+/// Adaptive schedule: target acceptance ratio at the start and end of a cooling segment.
+const ADAPT_TARGET: (f64, f64) = (0.5, 0.02);
+const ADAPT_FACTOR: f64 = 0.9;
+
+/// Temperature controller. Each reheat starts a new cooling segment
+/// that runs from the reheat temperature down to `final_temperature`.
+struct Thermostat {
+    schedule: CoolingSchedule,
+    t_start: f64,
+    t_final: f64,
+    total_steps: usize,
+    segment_start: usize,
+    segment_t0: f64,
+    temperature: f64,
+    recent: VecDeque<bool>,
+}
+
+impl Thermostat {
+    fn new(params: &Params) -> Self {
+        let t_start = params.temperature.max(0.0);
+        Self {
+            schedule: params.cooling,
+            t_start,
+            t_final: params.final_temperature.clamp(1e-6, t_start.max(1e-6)),
+            total_steps: params.max_steps,
+            segment_start: 0,
+            segment_t0: t_start,
+            temperature: t_start,
+            recent: VecDeque::with_capacity(ACCEPTANCE_WINDOW),
+        }
+    }
+
+    /// Fraction of the current segment completed at `step`.
+    fn progress(&self, step: usize) -> f64 {
+        let span = self.total_steps.saturating_sub(self.segment_start).max(1);
+        (step.saturating_sub(self.segment_start) as f64 / span as f64).min(1.0)
+    }
+
+    /// Advances the schedule to `step` and returns the temperature to use.
+    fn update(&mut self, step: usize) -> f64 {
+        let p = self.progress(step);
+        let (t0, tf) = (self.segment_t0, self.t_final);
+
+        self.temperature = match self.schedule {
+            CoolingSchedule::Linear => t0 + (tf - t0) * p,
+            CoolingSchedule::Geometric => if t0 > 0.0 { t0 * (tf / t0).powf(p) } else { 0.0 },
+            CoolingSchedule::Adaptive => {
+                if step > self.segment_start && (step - self.segment_start).is_multiple_of(ADAPT_INTERVAL) {
+                    let target = ADAPT_TARGET.0 + (ADAPT_TARGET.1 - ADAPT_TARGET.0) * p;
+                    if self.acceptance() > target {
+                        self.temperature *= ADAPT_FACTOR;
+                    } else {
+                        self.temperature /= ADAPT_FACTOR;
+                    }
+                }
+                self.temperature.clamp(tf.min(t0), t0)
+            }
+        };
+        self.temperature
+    }
+
+    fn record(&mut self, accepted: bool) {
+        if self.recent.len() >= ACCEPTANCE_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(accepted);
+    }
+
+    /// Rolling acceptance ratio over the last `ACCEPTANCE_WINDOW` moves.
+    fn acceptance(&self) -> f64 {
+        if self.recent.is_empty() {
+            return 0.0;
+        }
+        self.recent.iter().filter(|a| **a).count() as f64 / self.recent.len() as f64
+    }
+
+    /// Starts a new cooling segment at `fraction` of the starting temperature
+    /// (never below the current temperature). Returns the new temperature.
+    fn reheat(&mut self, step: usize, fraction: f64) -> f64 {
+        self.segment_start = step;
+        self.segment_t0 = (self.t_start * fraction).max(self.temperature);
+        self.temperature = self.segment_t0;
+        self.recent.clear();
+        self.temperature
+    }
+}
+
+/// Simulated Annealing on a single walker.
+///
+/// Moves come from the `Mutator` set and are accepted by the Metropolis criterion
+/// at a falling temperature. The move evaluator is meant to be cheap (single-point or
+/// lightly relaxed); an optional refinement evaluator fully relaxes the final best.
+pub struct SimulatedAnnealing {
+    evaluator: Arc<dyn Evaluator>,
+    refiner: Option<Arc<dyn Evaluator>>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    start: Option<Cluster>,
+    run: Option<Run>,
+}
+
+/// Walker state between moves.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    thermostat: Thermostat,
+    current: Cluster,
+    best: Cluster,
+    step: usize,
+    accepted: usize,
+    since_best: usize,
+    start_time: Instant,
+}
+
+impl SimulatedAnnealing {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        Self { evaluator, refiner: None, grid, params, start: None, run: None }
+    }
+
+    /// Relaxes the best structure with `evaluator` once annealing ends.
+    pub fn with_refinement(mut self, evaluator: Arc<dyn Evaluator>) -> Self {
+        self.refiner = Some(evaluator);
+        self
+    }
+
+    /// Starts from `cluster` instead of a random structure.
+    pub fn with_start(mut self, cluster: Cluster) -> Self {
+        self.start = Some(cluster);
+        self
+    }
+
+    /// Runs the annealing schedule from `current` for `max_steps` moves.
+    pub fn solve(self, current: Cluster, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self.with_start(current), tx);
+    }
+
+    /// One annealing move at the scheduled temperature.
+    fn anneal(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        if run.step >= self.params.max_steps { return Progress::Done; }
+        run.step += 1;
+        let i = run.step;
+
+        // Reheat on stagnation
+        run.since_best += 1;
+        if self.params.reheat_after > 0 && run.since_best >= self.params.reheat_after {
+            let t = run.thermostat.reheat(i, self.params.reheat_fraction);
+            run.since_best = 0;
+            let _ = tx.send(SolverEvent::Log(format!("SA stagnated at step {}. Reheating to {:.1} K", i, t)));
+        }
+        let temperature = run.thermostat.update(i);
+
+        match self.trial(&run.current, i, &mut run.rng) {
+            Err(kind) => {
+                run.failures.record(kind);
+                run.thermostat.record(false);
+            }
+            Ok(trial) => {
+                // Metropolis acceptance at the scheduled temperature
+                let e_new = trial.energy.unwrap_or(f64::MAX);
+                let delta = e_new - run.current.energy.unwrap_or(f64::MAX);
+                let accepted = metropolis(delta, temperature, &mut run.rng);
+
+                run.thermostat.record(accepted);
+                if accepted {
+                    run.accepted += 1;
+                    run.current = trial;
+                    if e_new < run.best.energy.unwrap_or(f64::MAX) {
+                        run.best = run.current.clone();
+                        run.since_best = 0;
+                        let _ = tx.send(SolverEvent::NewBest(run.best.clone()));
+                    }
+                }
+            }
+        }
+
+        self.report_step(tx, i, &run.current, &run.best, &run.thermostat, &run.failures);
+        if i < self.params.max_steps { Progress::Continue } else { Progress::Done }
+    }
+
+    /// A rattled copy of `current`, evaluated, or the reason it was rejected.
+    fn trial(&self, current: &Cluster, i: usize, rng: &mut impl Rng) -> Result<Cluster, EvalFailure> {
+        let mut trial = Mutator::new()
+            .rattle(self.params.step_size)
+            .apply(current, rng);
+        trial.origin = format!("SA_{}", i);
+
+        if !spatial::check_overlap(&trial, &self.grid) {
+            return Err(EvalFailure::Overlap);
+        }
+        solvers::relax_in_place(self.evaluator.as_ref(), &mut trial).map_err(|e| classify_failure(&e))?;
+        Ok(trial)
+    }
+
+    /// Maps the walker onto `GenStats`: avg/worst are the current energy, `acceptance` is the
+    /// rolling acceptance ratio and `mutation_rate` the fraction `T / T_start`.
+    fn report_step(
+        &self,
+        tx: &Sender<SolverEvent>,
+        iter: usize,
+        current: &Cluster,
+        best: &Cluster,
+        thermostat: &Thermostat,
+        failures: &FailureTally,
+    ) {
+        let e = current.energy.unwrap_or(0.0);
+        let relative_t = if thermostat.t_start > 0.0 { thermostat.temperature / thermostat.t_start } else { 0.0 };
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: iter,
+            best_energy: best.energy.unwrap_or(e),
+            avg_energy: e,
+            worst_energy: e,
+            diversity: 1.0,
+            valid_count: 1,
+            pop_size: 1,
+            mutation_rate: relative_t,
+            step_size: None,
+            temperature: Some(thermostat.temperature),
+            acceptance: Some(thermostat.acceptance()),
+            failures: failures.clone(),
+        }));
+        solvers::report_evaluator_every(self.evaluator.as_ref(), tx, iter);
+    }
+}
+
+impl Solver for SimulatedAnnealing {
+    fn name(&self) -> &str { "Simulated Annealing" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> anyhow::Result<()> {
+        let mut rng = seeded_rng(self.params.seed);
+
+        if self.params.max_steps == 0 {
+            bail!("Max steps set to 0. Exiting.");
+        }
+
+        let mut current = match self.start.take() {
+            Some(c) => c,
+            None => Cluster::new_random(&self.params.atom_counts, self.params.box_size, &self.grid, &mut rng)
+                .ok_or_else(|| anyhow!("CRITICAL: Failed to generate a starting structure."))?,
+        };
+
+        // 1. Initial Energy
+        if current.energy.is_none() {
+            solvers::relax_in_place(self.evaluator.as_ref(), &mut current)
+                .map_err(|e| anyhow!("Initial evaluation failed: {}", e))?;
+        }
+
+        let best = current.clone();
+        let _ = tx.send(SolverEvent::NewBest(best.clone()));
+
+        self.run = Some(Run {
+            rng,
+            failures: FailureTally::default(),
+            thermostat: Thermostat::new(&self.params),
+            current,
+            best,
+            step: 0,
+            accepted: 0,
+            since_best: 0,
+            start_time: Instant::now(),
+        });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.anneal(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.step, best: Some(r.best.clone()) })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let rate = if duration > 0.0 { run.step as f64 / duration } else { 0.0 };
+        let _ = tx.send(SolverEvent::Log(format!(
+            "SA Finished. Acceptance: {}/{}, final T = {:.2} K",
+            run.accepted, run.step, run.thermostat.temperature
+        )));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+        report_evaluator(self.evaluator.as_ref(), tx);
+
+        // 3. Optional full relaxation of the annealed best
+        if let Some(refiner) = &self.refiner {
+            let _ = tx.send(SolverEvent::Log("Refining annealed best...".to_string()));
+            let mut refined = run.best.clone();
+            refined.origin = "SA_Refined".to_string();
+            match solvers::relax_in_place(refiner.as_ref(), &mut refined) {
+                Ok(()) => {
+                    let _ = tx.send(SolverEvent::Log(format!("Refined energy: {:.5} eV", refined.energy.unwrap_or(0.0))));
+                    let _ = tx.send(SolverEvent::NewBest(refined));
+                }
+                Err(e) => {
+                    let _ = tx.send(SolverEvent::Log(format!("Refinement failed: {}", e)));
+                }
+            }
+        }
+    }
+}
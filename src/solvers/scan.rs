@@ -0,0 +1,196 @@
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::Result;
+use crossbeam_channel::Sender;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::core::domain::{Params, ClusterStatus};
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::Evaluator;
+use crate::analysis::topology;
+use crate::solvers::hof::HallOfFame;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, BoxScanStats, report_evaluator, seeded_rng};
+
+/// Size of the Hall of Fame kept across all box sizes.
+const HOF_CAPACITY: usize = 50;
+
+/// Box sizes visited by a scan: `scan_steps` evenly spaced values from `scan_box_min`
+/// to `scan_box_max` inclusive.
+pub fn box_sizes(params: &Params) -> Vec<f64> {
+    let lo = params.scan_box_min.min(params.scan_box_max);
+    let hi = params.scan_box_min.max(params.scan_box_max);
+    match params.scan_steps {
+        0 => Vec::new(),
+        1 => vec![lo],
+        n => (0..n).map(|k| lo + (hi - lo) * k as f64 / (n - 1) as f64).collect(),
+    }
+}
+
+/// The original KLMC "scan box" search.
+///
+/// For every box size in the scan range, `population_size` random structures are generated
+/// and relaxed in parallel. The energy distribution per box size goes to the UI and every
+/// distinct relaxed minimum goes through one Hall of Fame.
+pub struct ScanBox {
+    evaluator: Arc<dyn Evaluator>,
+    grid: Arc<InteractionGrid>,
+    params: Params,
+    run: Option<Run>,
+}
+
+/// Scan state between box sizes.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    hof: HallOfFame,
+    sizes: Vec<f64>,
+    scan: Vec<BoxScanStats>,
+    start_time: Instant,
+}
+
+impl ScanBox {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        params: Params
+    ) -> Self {
+        Self { evaluator, grid, params, run: None }
+    }
+
+    pub fn solve(mut self, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self, tx);
+    }
+
+    /// Samples and relaxes `population_size` structures in the next box size.
+    fn scan_next(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        let k = run.scan.len();
+        let Some(&box_size) = run.sizes.get(k) else { return Progress::Done; };
+
+        // 1. Random structures in this box (generation can fail in boxes that are too small)
+        let mut batch = solvers::random_batch(
+            &self.params.atom_counts, box_size, &self.grid, self.params.population_size,
+            &mut run.rng, &mut run.failures, |i| format!("Scan_{:.2}_{}", box_size, i),
+        );
+
+        // 2. Parallel relaxation
+        batch.par_iter_mut().for_each(|c| { let _ = solvers::relax_in_place(self.evaluator.as_ref(), c); });
+
+        let mut energies = Vec::with_capacity(batch.len());
+        for c in &mut batch {
+            match c.status {
+                ClusterStatus::Evaluated => {
+                    c.hash_key = Some(topology::generate_hash_key(c, 1.5));
+                    energies.push(c.energy.unwrap_or(0.0));
+                }
+                ClusterStatus::Discarded(kind) => run.failures.record(kind),
+                _ => {}
+            }
+        }
+
+        // 3. Hall of Fame
+        for c in batch.iter().filter(|c| c.energy.is_some()) {
+            if run.hof.insert(c, k) {
+                let _ = tx.send(SolverEvent::NewBest(c.clone()));
+            }
+        }
+
+        // 4. Energy distribution at this box size
+        let stats = BoxScanStats::from_energies(box_size, self.params.population_size, &energies);
+        let _ = tx.send(SolverEvent::Log(match stats.best_energy {
+            Some(e) => format!("Box {:.2} Å: {}/{} relaxed, best {:.5} eV", box_size, stats.valid, stats.samples, e),
+            None => format!("Box {:.2} Å: no structure relaxed", box_size),
+        }));
+        run.scan.push(stats);
+        let _ = tx.send(SolverEvent::BoxScanUpdate(run.scan.clone()));
+
+        self.report_box(tx, k + 1, &energies, &run.hof, &run.failures);
+
+        if run.scan.len() < run.sizes.len() { Progress::Continue } else { Progress::Done }
+    }
+
+    /// Maps one box size onto `GenStats` (`generation` counts box sizes done).
+    fn report_box(
+        &self,
+        tx: &Sender<SolverEvent>,
+        step: usize,
+        energies: &[f64],
+        hof: &HallOfFame,
+        failures: &FailureTally,
+    ) {
+        let (avg, worst) = if energies.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (energies.iter().sum::<f64>() / energies.len() as f64, energies.iter().cloned().fold(f64::MIN, f64::max))
+        };
+
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: step,
+            best_energy: hof.best().and_then(|c| c.energy).unwrap_or(0.0),
+            avg_energy: avg,
+            worst_energy: worst,
+            diversity: if energies.is_empty() { 0.0 } else { hof.len() as f64 / (step * self.params.population_size) as f64 },
+            valid_count: energies.len(),
+            pop_size: self.params.population_size,
+            mutation_rate: 0.0,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
+
+impl Solver for ScanBox {
+    fn name(&self) -> &str { "ScanBox" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let sizes = box_sizes(&self.params);
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Scanning {} box sizes ({:.2} .. {:.2} Å), {} structures each",
+            sizes.len(),
+            sizes.first().copied().unwrap_or(0.0),
+            sizes.last().copied().unwrap_or(0.0),
+            self.params.population_size
+        )));
+        self.run = Some(Run {
+            rng: seeded_rng(self.params.seed),
+            failures: FailureTally::default(),
+            hof: HallOfFame::new(HOF_CAPACITY),
+            sizes,
+            scan: Vec::new(),
+            start_time: Instant::now(),
+        });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.scan_next(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.scan.len(), best: r.hof.best().cloned() })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let evals = run.scan.len() * self.params.population_size;
+        let rate = if duration > 0.0 { evals as f64 / duration } else { 0.0 };
+        let best_box = run.scan.iter()
+            .filter(|s| s.best_energy.is_some())
+            .min_by(|a, b| a.best_energy.partial_cmp(&b.best_energy).unwrap_or(std::cmp::Ordering::Equal));
+        let _ = tx.send(SolverEvent::Log(match best_box {
+            Some(s) => format!("Scan Finished. {} distinct minima, lowest from box {:.2} Å", run.hof.len(), s.box_size),
+            None => "Scan Finished. No structure relaxed.".to_string(),
+        }));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
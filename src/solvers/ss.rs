@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use anyhow::{anyhow, bail, Result};
+use crossbeam_channel::Sender;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::domain::{Cluster, Params};
+use crate::core::chemistry::InteractionGrid;
+use crate::engine::evaluator::{Evaluator, classify_failure};
+use crate::analysis::symmetry;
+use crate::solvers::acceptance::metropolis;
+use crate::solvers::bh::BasinHopping;
+use crate::solvers::{self, Control, Progress, Solver, SolverState, SolverEvent, GenStats, FailureTally, report_evaluator, seeded_rng};
+
+/// Substitutable sites of a host lattice and the species that share them.
+#[derive(Debug, Clone)]
+pub struct Sublattice {
+    /// Host atom indices whose species may change.
+    pub sites: Vec<usize>,
+    /// `(element_id, fraction)` of the sites each species should occupy.
+    pub fractions: Vec<(usize, f64)>,
+}
+
+impl Sublattice {
+    /// Whole-site counts per species, rounded by largest remainder so they sum to `sites.len()`.
+    pub fn counts(&self) -> Vec<(usize, usize)> {
+        let n = self.sites.len();
+        let total: f64 = self.fractions.iter().map(|(_, f)| f.max(0.0)).sum();
+        if total <= 0.0 { return Vec::new(); }
+
+        let exact: Vec<f64> = self.fractions.iter().map(|(_, f)| f.max(0.0) / total * n as f64).collect();
+        let mut counts: Vec<usize> = exact.iter().map(|x| x.floor() as usize).collect();
+        let mut order: Vec<usize> = (0..exact.len()).collect();
+        order.sort_by(|&a, &b| (exact[b] - exact[b].floor()).partial_cmp(&(exact[a] - exact[a].floor())).unwrap_or(std::cmp::Ordering::Equal));
+        let missing = n - counts.iter().sum::<usize>();
+        for &i in order.iter().take(missing) {
+            counts[i] += 1;
+        }
+        self.fractions.iter().zip(counts).map(|(&(species, _), c)| (species, c)).collect()
+    }
+}
+
+/// Site-occupancy search on a fixed periodic host (Solid Solution).
+///
+/// Swap Monte Carlo over orderings: each step exchanges two sites holding different species,
+/// relaxes the resulting structure with the evaluator and applies the Metropolis criterion at
+/// `params.temperature`. Orderings are reduced to a canonical form under the host's symmetry,
+/// so equivalent configurations are relaxed once and their energy reused.
+pub struct SolidSolution {
+    hopper: BasinHopping,
+    evaluator: Arc<dyn Evaluator>,
+    host: Cluster,
+    sublattice: Sublattice,
+    params: Params,
+    run: Option<Run>,
+}
+
+/// Monte Carlo state between swaps.
+struct Run {
+    rng: ChaCha8Rng,
+    failures: FailureTally,
+    /// Host symmetry, restricted to the substitutable sites.
+    perms: Vec<Vec<usize>>,
+    occupancy: Vec<usize>,
+    /// Canonical ordering -> relaxed structure (None if the relaxation failed).
+    cache: HashMap<Vec<usize>, Option<Cluster>>,
+    requests: usize,
+    current: Cluster,
+    best: Cluster,
+    mixed: bool,
+    step: usize,
+    accepted: usize,
+    start_time: Instant,
+}
+
+impl SolidSolution {
+    pub fn new(
+        evaluator: Arc<dyn Evaluator>,
+        grid: Arc<InteractionGrid>,
+        host: Cluster,
+        sublattice: Sublattice,
+        params: Params
+    ) -> Result<Self> {
+        if host.lattice.is_none() {
+            bail!("Solid solution host must be periodic (Cluster::lattice is None)");
+        }
+        if sublattice.sites.is_empty() || sublattice.fractions.is_empty() {
+            bail!("Solid solution needs at least one substitutable site and one species");
+        }
+        if let Some(&s) = sublattice.sites.iter().find(|&&s| s >= host.atoms.len()) {
+            bail!("Substitutable site {} is outside the host ({} atoms)", s, host.atoms.len());
+        }
+        if sublattice.fractions.iter().map(|(_, f)| f.max(0.0)).sum::<f64>() <= 0.0 {
+            bail!("Site fractions must sum to a positive value");
+        }
+        let hopper = BasinHopping::new(evaluator.clone(), grid, params.clone());
+        Ok(Self { hopper, evaluator, host, sublattice, params, run: None })
+    }
+
+    pub fn solve(mut self, tx: Sender<SolverEvent>) {
+        solvers::run(&mut self, tx);
+    }
+
+    /// One swap move between two sites holding different species.
+    fn swap(&self, run: &mut Run, tx: &Sender<SolverEvent>) -> Progress {
+        if !run.mixed || run.step >= self.params.max_steps { return Progress::Done; }
+        run.step += 1;
+        let i = run.step;
+
+        let (a, b) = loop {
+            let a = run.rng.gen_range(0..run.occupancy.len());
+            let b = run.rng.gen_range(0..run.occupancy.len());
+            if run.occupancy[a] != run.occupancy[b] { break (a, b); }
+        };
+        let mut trial = run.occupancy.clone();
+        trial.swap(a, b);
+
+        if let Some(candidate) = self.ordering(&trial, &run.perms, &mut run.cache, &mut run.requests, &mut run.failures) {
+            let delta = candidate.energy.unwrap_or(f64::MAX) - run.current.energy.unwrap_or(f64::MAX);
+            if metropolis(delta, self.params.temperature, &mut run.rng) {
+                run.occupancy = trial;
+                run.current = candidate;
+                run.accepted += 1;
+                if run.current.energy < run.best.energy {
+                    run.best = run.current.clone();
+                    let _ = tx.send(SolverEvent::NewBest(run.best.clone()));
+                }
+            }
+        }
+
+        let distinct = run.cache.len() as f64 / run.requests.max(1) as f64;
+        self.report_step(tx, i, &run.current, &run.best, distinct, &run.failures);
+        if i < self.params.max_steps { Progress::Continue } else { Progress::Done }
+    }
+
+    /// The relaxed structure for an ordering. Symmetry-equivalent orderings are looked up
+    /// in `cache` instead of being relaxed again.
+    fn ordering(
+        &self,
+        occupancy: &[usize],
+        perms: &[Vec<usize>],
+        cache: &mut HashMap<Vec<usize>, Option<Cluster>>,
+        requests: &mut usize,
+        failures: &mut FailureTally,
+    ) -> Option<Cluster> {
+        *requests += 1;
+        let key = symmetry::canonical_occupancy(occupancy, perms);
+        if let Some(hit) = cache.get(&key) {
+            return hit.clone();
+        }
+
+        let mut cluster = self.host.clone();
+        cluster.origin = format!("SS_{}", cache.len());
+        cluster.energy = None;
+        for (&site, &species) in self.sublattice.sites.iter().zip(occupancy) {
+            cluster.atoms[site].element_id = species;
+        }
+
+        let relaxed = match self.hopper.relax(&mut cluster) {
+            Ok(()) => {
+                cluster.hash_key = Some(key.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","));
+                Some(cluster)
+            }
+            Err(e) => {
+                failures.record(classify_failure(&e));
+                None
+            }
+        };
+        cache.insert(key, relaxed.clone());
+        relaxed
+    }
+
+    /// Maps the walker onto `GenStats`: `diversity` is the fraction of requested orderings
+    /// that were symmetry-distinct (and therefore relaxed).
+    fn report_step(
+        &self,
+        tx: &Sender<SolverEvent>,
+        iter: usize,
+        current: &Cluster,
+        best: &Cluster,
+        distinct: f64,
+        failures: &FailureTally,
+    ) {
+        let _ = tx.send(SolverEvent::GenerationUpdate(GenStats {
+            generation: iter,
+            best_energy: best.energy.unwrap_or(0.0),
+            avg_energy: current.energy.unwrap_or(0.0),
+            worst_energy: current.energy.unwrap_or(0.0),
+            diversity: distinct,
+            valid_count: 1,
+            pop_size: 1,
+            mutation_rate: 0.0,
+            step_size: None,
+            temperature: None,
+            acceptance: None,
+            failures: failures.clone(),
+        }));
+        solvers::report_evaluator_every(self.evaluator.as_ref(), tx, iter);
+    }
+}
+
+impl Solver for SolidSolution {
+    fn name(&self) -> &str { "Solid Solution" }
+
+    fn init(&mut self, tx: &Sender<SolverEvent>) -> Result<()> {
+        let mut rng = seeded_rng(self.params.seed);
+        let mut failures = FailureTally::default();
+
+        // 1. Host symmetry, restricted to the substitutable sites
+        let perms = symmetry::site_permutations(&self.host, &self.sublattice.sites);
+        let counts = self.sublattice.counts();
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Solid solution: {} sites, {} symmetry operations, counts {:?}",
+            self.sublattice.sites.len(), perms.len(), counts
+        )));
+
+        // 2. Random starting ordering
+        let mut occupancy: Vec<usize> = counts.iter().flat_map(|&(species, n)| std::iter::repeat_n(species, n)).collect();
+        occupancy.shuffle(&mut rng);
+
+        let mut cache = HashMap::new();
+        let mut requests = 0;
+        let current = self.ordering(&occupancy, &perms, &mut cache, &mut requests, &mut failures)
+            .ok_or_else(|| anyhow!("CRITICAL: Initial ordering failed to relax."))?;
+        let _ = tx.send(SolverEvent::NewBest(current.clone()));
+
+        let mixed = counts.iter().filter(|(_, n)| *n > 0).count() > 1;
+        if !mixed {
+            let _ = tx.send(SolverEvent::Log("Only one species on the sublattice: nothing to order.".to_string()));
+        }
+
+        self.run = Some(Run {
+            rng,
+            failures,
+            perms,
+            occupancy,
+            cache,
+            requests,
+            best: current.clone(),
+            current,
+            mixed,
+            step: 0,
+            accepted: 0,
+            start_time: Instant::now(),
+        });
+        Ok(())
+    }
+
+    fn step(&mut self, tx: &Sender<SolverEvent>, _control: &Control) -> Progress {
+        let Some(mut run) = self.run.take() else { return Progress::Done; };
+        let progress = self.swap(&mut run, tx);
+        self.run = Some(run);
+        progress
+    }
+
+    fn state(&self) -> SolverState {
+        self.run.as_ref()
+            .map(|r| SolverState { step: r.step, best: Some(r.best.clone()) })
+            .unwrap_or_default()
+    }
+
+    fn finish(&mut self, tx: &Sender<SolverEvent>) {
+        let Some(run) = &self.run else { return; };
+        let duration = run.start_time.elapsed().as_secs_f64();
+        let rate = if duration > 0.0 { run.step as f64 / duration } else { 0.0 };
+        let _ = tx.send(SolverEvent::Log(format!(
+            "Solid solution finished. Acceptance {}/{}, {} distinct orderings relaxed ({} requested)",
+            run.accepted, run.step, run.cache.len(), run.requests
+        )));
+        let _ = tx.send(SolverEvent::WorkerHeartbeat(rate));
+        report_evaluator(self.evaluator.as_ref(), tx);
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use anyhow::Result;
+
+use crate::core::domain::{Cluster, Params};
+use crate::engine::evaluator::{Evaluator, EvaluationResult};
+use crate::engine::instrument::EvaluatorStats;
+use crate::analysis::topology;
+use crate::solvers::SolverState;
+
+/// Bond cutoff for the topology hash that identifies a minimum (Å), as used by the solvers.
+const HASH_CUTOFF: f64 = 1.5;
+/// Smallest drop in the best energy that counts as an improvement (eV).
+const IMPROVEMENT: f64 = 1e-5;
+
+/// Why a run ended, as reported by `SolverEvent::Finished`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    /// `max_steps` used up (or nothing left to search).
+    Completed,
+    TargetEnergy(f64),
+    /// Elapsed seconds.
+    TimeLimit(f64),
+    EvaluationLimit(usize),
+    /// Steps without a new best.
+    Stalled(usize),
+    /// Times the best structure was found.
+    Converged(usize),
+    /// `SolverCommand::Stop`.
+    Stopped,
+    /// The solver could not start.
+    Failed,
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Completed => write!(f, "step budget used up"),
+            StopReason::TargetEnergy(e) => write!(f, "target energy reached ({:.5} eV)", e),
+            StopReason::TimeLimit(s) => write!(f, "time limit reached after {:.1} s", s),
+            StopReason::EvaluationLimit(n) => write!(f, "evaluation limit reached ({} evaluations)", n),
+            StopReason::Stalled(n) => write!(f, "no improvement for {} steps", n),
+            StopReason::Converged(k) => write!(f, "best structure found {} times", k),
+            StopReason::Stopped => write!(f, "stopped on request"),
+            StopReason::Failed => write!(f, "failed to start"),
+        }
+    }
+}
+
+/// Evaluation count and repeat hits on the solver's best minimum, shared with the monitored evaluator.
+#[derive(Debug)]
+struct Tally {
+    evaluations: AtomicUsize,
+    /// Hash key of the best structure at the last check (None until one is known, or if
+    /// repeat hits are off).
+    best_key: Mutex<Option<String>>,
+    /// Times `best_key` was found.
+    hits: AtomicUsize,
+}
+
+/// Termination conditions from `Params`, checked by the runner after every step. Any one of
+/// them ends the run; `max_steps` is left to the solver.
+///
+/// The evaluation budget and the repeat-hit count only see the evaluator wrapped by `monitor`.
+/// A hit is any evaluation that relaxes into the solver's best structure, compared by topology
+/// hash; the count restarts whenever the best changes.
+#[derive(Debug)]
+pub struct StoppingCriteria {
+    target_energy: Option<f64>,
+    time_limit: f64,
+    max_evaluations: usize,
+    stall_steps: usize,
+    repeat_hits: usize,
+    tally: Arc<Tally>,
+    start: Instant,
+    best: f64,
+    best_step: usize,
+}
+
+impl Default for StoppingCriteria {
+    fn default() -> Self {
+        Self::new(&Params::default())
+    }
+}
+
+impl StoppingCriteria {
+    pub fn new(params: &Params) -> Self {
+        Self {
+            target_energy: params.target_energy,
+            time_limit: params.time_limit.max(0.0),
+            max_evaluations: params.max_evaluations,
+            stall_steps: params.stall_steps,
+            repeat_hits: params.repeat_hits,
+            tally: Arc::new(Tally { evaluations: AtomicUsize::new(0), best_key: Mutex::new(None), hits: AtomicUsize::new(0) }),
+            start: Instant::now(),
+            best: f64::INFINITY,
+            best_step: 0,
+        }
+    }
+
+    /// True if any criterion is set.
+    pub fn is_active(&self) -> bool {
+        self.target_energy.is_some()
+            || self.time_limit > 0.0
+            || self.max_evaluations > 0
+            || self.stall_steps > 0
+            || self.repeat_hits > 0
+    }
+
+    /// Wraps `evaluator` so that its calls count towards the evaluation and repeat-hit criteria.
+    /// Monitor only the model the final energies come from.
+    pub fn monitor(&self, evaluator: Arc<dyn Evaluator>) -> Arc<dyn Evaluator> {
+        Arc::new(MonitoredEvaluator { inner: evaluator, tally: self.tally.clone() })
+    }
+
+    /// Restarts the wall clock (call when the run starts).
+    pub fn start(&mut self) {
+        self.start = Instant::now();
+    }
+
+    pub fn evaluations(&self) -> usize {
+        self.tally.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Times the current best structure has been found by the monitored evaluator.
+    pub fn hits(&self) -> usize {
+        self.tally.hits.load(Ordering::Relaxed)
+    }
+
+    /// The first criterion `state` meets, if any.
+    pub fn check(&mut self, state: &SolverState) -> Option<StopReason> {
+        let energy = state.best.as_ref().and_then(|c| c.energy);
+        if let Some(e) = energy {
+            if e < self.best - IMPROVEMENT {
+                self.best = e;
+                self.best_step = state.step;
+            }
+        }
+        if self.repeat_hits > 0 {
+            self.track_best(state.best.as_ref());
+        }
+
+        if let (Some(target), Some(e)) = (self.target_energy, energy) {
+            if e <= target {
+                return Some(StopReason::TargetEnergy(e));
+            }
+        }
+        let elapsed = self.start.elapsed().as_secs_f64();
+        if self.time_limit > 0.0 && elapsed >= self.time_limit {
+            return Some(StopReason::TimeLimit(elapsed));
+        }
+        let evaluations = self.evaluations();
+        if self.max_evaluations > 0 && evaluations >= self.max_evaluations {
+            return Some(StopReason::EvaluationLimit(evaluations));
+        }
+        let stalled = state.step.saturating_sub(self.best_step);
+        if self.stall_steps > 0 && stalled >= self.stall_steps {
+            return Some(StopReason::Stalled(stalled));
+        }
+        let hits = self.hits();
+        if self.repeat_hits > 0 && hits >= self.repeat_hits {
+            return Some(StopReason::Converged(hits));
+        }
+        None
+    }
+
+    /// Points the repeat-hit count at `best`, restarting it at one (the find that made it
+    /// the best) when the best structure changed.
+    fn track_best(&self, best: Option<&Cluster>) {
+        let Some(best) = best else { return; };
+        let key = best.hash_key.clone().unwrap_or_else(|| topology::generate_hash_key(best, HASH_CUTOFF));
+        let mut best_key = self.tally.best_key.lock().unwrap_or_else(|e| e.into_inner());
+        if best_key.as_deref() != Some(key.as_str()) {
+            *best_key = Some(key);
+            self.tally.hits.store(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Evaluator wrapper feeding a `StoppingCriteria` tally.
+struct MonitoredEvaluator {
+    inner: Arc<dyn Evaluator>,
+    tally: Arc<Tally>,
+}
+
+impl Evaluator for MonitoredEvaluator {
+    fn name(&self) -> &str { self.inner.name() }
+
+    fn evaluate(&self, cluster: &Cluster) -> Result<EvaluationResult> {
+        let res = self.inner.evaluate(cluster);
+        self.tally.evaluations.fetch_add(1, Ordering::Relaxed);
+        if let Ok(r) = &res {
+            let best_key = self.tally.best_key.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            if let Some(best_key) = best_key {
+                let relaxed = r.relaxed_cluster.as_ref().unwrap_or(cluster);
+                if topology::generate_hash_key(relaxed, HASH_CUTOFF) == best_key {
+                    self.tally.hits.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+        res
+    }
+
+    fn stats(&self) -> Option<EvaluatorStats> { self.inner.stats() }
+}
@@ -1,5 +1,7 @@
+use std::sync::Arc;
 use anyhow::Result;
-use klmc_ultimate::core::domain::Cluster;
+use klmc_ultimate::core::chemistry::InteractionGrid;
+use klmc_ultimate::core::domain::{Cluster, Species};
 use klmc_ultimate::engine::evaluator::{Evaluator, EvaluationResult};
 
 pub struct MockEvaluator;
@@ -16,9 +18,21 @@ impl Evaluator for MockEvaluator {
         Ok(EvaluationResult {
             energy,
             gradient_norm: Some(0.1),
+            iterations: None,
             relaxed_cluster: Some(cluster.clone()),
+            properties: Default::default(),
         })
     }
 
     fn name(&self) -> &str { "Mock Evaluator" }
 }
+
+/// Interaction grid for two species A and B of covalent radius 0.5.
+#[allow(dead_code)] // Not every test crate builds clusters
+pub fn ab_grid() -> Arc<InteractionGrid> {
+    let species = vec![
+        Species { symbol: "A".into(), radius_covalent: 0.5, ..Default::default() },
+        Species { symbol: "B".into(), radius_covalent: 0.5, ..Default::default() },
+    ];
+    Arc::new(InteractionGrid::new(&species, 0.5))
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use klmc_ultimate::analysis::{alignment, pareto, symmetry};
+use klmc_ultimate::core::domain::{Cluster, Atom, Lattice};
+use nalgebra::{Point3, Vector3};
+
+fn cluster_from(atoms: &[(usize, [f64; 3])]) -> Cluster {
+    let mut c = Cluster::new("Test");
+    for (id, p) in atoms {
+        c.atoms.push(Atom {
+            element_id: *id,
+            position: Point3::new(p[0], p[1], p[2]),
+            velocity: Vector3::zeros(),
+            force: Vector3::zeros(),
+            is_fixed: false,
+        });
+    }
+    c
+}
+
+#[test]
+fn test_hungarian_assignment() {
+    let cost = vec![
+        vec![4.0, 1.0, 3.0],
+        vec![2.0, 0.0, 5.0],
+        vec![3.0, 2.0, 2.0],
+    ];
+    // Optimal: 0->1, 1->0, 2->2 (total 5)
+    assert_eq!(alignment::assignment(&cost), vec![1, 0, 2]);
+}
+
+#[test]
+fn test_match_atoms_recovers_permutation() {
+    let reference = cluster_from(&[
+        (0, [0.0, 0.0, 0.0]),
+        (0, [2.0, 0.0, 0.0]),
+        (1, [0.0, 2.0, 0.0]),
+        (1, [0.0, 0.0, 2.0]),
+    ]);
+    // Same structure, shifted and relabelled; the species-1 atoms swap places
+    let shift = [5.0, -1.0, 0.5];
+    let order = [1, 0, 3, 2];
+    let target_atoms: Vec<(usize, [f64; 3])> = order.iter().map(|&i| {
+        let a = &reference.atoms[i];
+        (a.element_id, [a.position.x + shift[0], a.position.y + shift[1], a.position.z + shift[2]])
+    }).collect();
+    let target = cluster_from(&target_atoms);
+
+    assert_eq!(alignment::match_atoms(&reference, &target).unwrap(), vec![1, 0, 3, 2]);
+
+    let aligned = alignment::aligned_positions(&reference, &target).unwrap();
+    for (a, p) in reference.atoms.iter().zip(&aligned) {
+        assert!((a.position - p).norm() < 1e-12);
+    }
+
+    let wrong = cluster_from(&[(0, [0.0; 3]), (0, [1.0, 0.0, 0.0]), (0, [2.0, 0.0, 0.0]), (1, [3.0, 0.0, 0.0])]);
+    assert!(alignment::match_atoms(&reference, &wrong).is_none());
+}
+
+#[test]
+fn test_symmetry_equivalent_orderings() {
+    // 2x2x2 simple cubic supercell: every pair of sites is an edge, face or body diagonal
+    let mut atoms = Vec::new();
+    for x in 0..2 {
+        for y in 0..2 {
+            for z in 0..2 {
+                atoms.push((0, [x as f64 * 2.0, y as f64 * 2.0, z as f64 * 2.0]));
+            }
+        }
+    }
+    let mut host = cluster_from(&atoms);
+    host.lattice = Lattice::new(Vector3::new(4.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(0.0, 0.0, 4.0));
+    let sites: Vec<usize> = (0..8).collect();
+
+    let perms = symmetry::site_permutations(&host, &sites);
+    assert_eq!(perms.len(), 48);
+
+    let mut keys = HashSet::new();
+    for a in 0..8 {
+        for b in (a + 1)..8 {
+            let mut occupancy = vec![0; 8];
+            occupancy[a] = 1;
+            occupancy[b] = 1;
+            keys.insert(symmetry::canonical_occupancy(&occupancy, &perms));
+        }
+    }
+    assert_eq!(keys.len(), 3);
+
+    // Without a lattice there is no symmetry to exploit
+    host.lattice = None;
+    assert_eq!(symmetry::site_permutations(&host, &sites).len(), 1);
+}
+
+#[test]
+fn test_pareto_fronts_and_crowding() {
+    let points = vec![
+        vec![0.0, 4.0],
+        vec![1.0, 2.0],
+        vec![2.0, 1.0],
+        vec![4.0, 0.0],
+        vec![2.0, 3.0], // dominated by (1, 2)
+        vec![3.0, 3.0], // dominated by (2, 3)
+    ];
+    let fronts = pareto::non_dominated_fronts(&points);
+    assert_eq!(fronts.len(), 3);
+    assert_eq!(fronts[0], vec![0, 1, 2, 3]);
+    assert_eq!(fronts[1], vec![4]);
+    assert_eq!(fronts[2], vec![5]);
+
+    let crowding = pareto::crowding_distance(&points, &fronts[0]);
+    assert!(crowding[0].is_infinite() && crowding[3].is_infinite());
+    assert!((crowding[1] - 1.25).abs() < 1e-12);
+    assert!((crowding[2] - 1.25).abs() < 1e-12);
+
+    assert!(!pareto::dominates(&[1.0, 1.0], &[1.0, 1.0]));
+}
+
+#[test]
+fn test_point_symmetry_score() {
+    // Rectangular cuboid: invariant under every D2h operation
+    let mut atoms = Vec::new();
+    for sx in [-1.0, 1.0] {
+        for sy in [-2.0, 2.0] {
+            for sz in [-3.0, 3.0] {
+                atoms.push((0, [sx, sy, sz]));
+            }
+        }
+    }
+    let cuboid = cluster_from(&atoms);
+    assert!((symmetry::point_symmetry_score(&cuboid, 0.1) - 1.0).abs() < 1e-9);
+
+    // Relabelling one corner breaks every operation for that atom and its images
+    atoms[0].0 = 1;
+    let broken = cluster_from(&atoms);
+    let score = symmetry::point_symmetry_score(&broken, 0.1);
+    assert!(score < 1.0 && score > 0.0);
+}
@@ -0,0 +1,54 @@
+use klmc_ultimate::analysis::benchmark;
+use klmc_ultimate::core::domain::{AlgorithmType, Params};
+use klmc_ultimate::engine::native::potentials::{Dzugutov, Morse, PairPotential};
+
+#[test]
+fn test_potential_derivatives() {
+    let potentials: Vec<Box<dyn PairPotential>> = vec![Box::new(Morse::reduced(6.0)), Box::new(Dzugutov::default())];
+    let h = 1e-6;
+    for p in &potentials {
+        for r in [0.9, 1.1, 1.5, 1.9] {
+            let (_, de) = p.energy_deriv(0, 0, r);
+            let fd = (p.energy_deriv(0, 0, r + h).0 - p.energy_deriv(0, 0, r - h).0) / (2.0 * h);
+            assert!((de - fd).abs() < 1e-5, "{} dV/dr mismatch at r = {}: {} vs {}", p.name(), r, de, fd);
+        }
+    }
+}
+
+#[test]
+fn test_lj7_known_minimum_regression() {
+    let problem = benchmark::find("LJ7").expect("LJ7 missing from catalogue");
+
+    let ga = Params {
+        algorithm: AlgorithmType::GeneticAlgorithm,
+        population_size: 20,
+        elitism_count: 2,
+        max_steps: 10,
+        seed: 1,
+        ..Default::default()
+    };
+    let bh = Params {
+        algorithm: AlgorithmType::BasinHopping,
+        temperature: 0.8 / 8.617333262e-5, // kT ≈ 0.8 ε
+        step_size: 0.4,
+        max_steps: 200,
+        seed: 1,
+        ..Default::default()
+    };
+
+    let mh = Params {
+        algorithm: AlgorithmType::MinimaHopping,
+        max_steps: 50,
+        md_kinetic_energy: 0.5,
+        hop_ediff: 0.5,
+        seed: 1,
+        ..Default::default()
+    };
+
+    for base in [ga, bh, mh] {
+        let report = benchmark::run_benchmark(&problem, &base, 2, 1e-3);
+        assert_eq!(report.success_rate(), 1.0, "{:?} missed LJ7: best {}", report.algorithm, report.best_energy());
+        assert!(report.mean_evals_to_hit().unwrap() <= report.runs[0].evaluations as f64);
+        assert!(report.best_energy() >= problem.global_minimum - 1e-6);
+    }
+}
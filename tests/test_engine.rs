@@ -0,0 +1,108 @@
+use klmc_ultimate::core::domain::{Cluster, Atom, EvalFailure};
+use klmc_ultimate::engine::evaluator::{Evaluator, classify_failure, failure};
+use klmc_ultimate::engine::instrument::InstrumentedEvaluator;
+use klmc_ultimate::engine::native::forcefield::{self, MdSettings, NativeEvaluator};
+use klmc_ultimate::engine::native::potentials::LennardJones;
+use klmc_ultimate::engine::screening::{ScreeningEvaluator, ScreeningPolicy, ScreenedOut};
+use nalgebra::{Point3, Vector3};
+use std::sync::Arc;
+use crate::common::MockEvaluator;
+
+mod common;
+
+fn cluster_from(positions: &[[f64; 3]]) -> Cluster {
+    let mut c = Cluster::new("Test");
+    for p in positions {
+        c.atoms.push(Atom {
+            element_id: 0,
+            position: Point3::new(p[0], p[1], p[2]),
+            velocity: Vector3::zeros(),
+            force: Vector3::zeros(),
+            is_fixed: false,
+        });
+    }
+    c
+}
+
+#[test]
+fn test_native_lj_dimer() {
+    let evaluator = NativeEvaluator::new(Arc::new(LennardJones::reduced()));
+    let dimer = cluster_from(&[[0.0, 0.0, 0.0], [1.5, 0.0, 0.0]]);
+
+    let res = evaluator.evaluate(&dimer).expect("LJ dimer relaxation failed");
+    let relaxed = res.relaxed_cluster.unwrap();
+    let r = (relaxed.atoms[0].position - relaxed.atoms[1].position).norm();
+
+    assert!((res.energy + 1.0).abs() < 1e-6);
+    assert!((r - 2f64.powf(1.0 / 6.0)).abs() < 1e-3);
+}
+
+#[test]
+fn test_md_conserves_energy_and_counts_minima() {
+    let lj = LennardJones::reduced();
+    let mut dimer = cluster_from(&[[0.0, 0.0, 0.0], [1.3, 0.0, 0.0]]);
+    let (e0, _) = forcefield::energy_forces(&lj, &dimer);
+
+    let settings = MdSettings { timestep: 0.002, minima: 2, max_steps: 10_000 };
+    let md = forcefield::molecular_dynamics(&lj, &mut dimer, &settings);
+
+    // Released from rest, the dimer oscillates through the well bottom twice
+    assert_eq!(md.minima, 2);
+    assert!(md.steps < settings.max_steps);
+    let kinetic: f64 = dimer.atoms.iter().map(|a| 0.5 * a.velocity.norm_squared()).sum();
+    assert!((md.potential_energy + kinetic - e0).abs() < 1e-3);
+}
+
+#[test]
+fn test_screening_rejects_outside_window() {
+    let screen = ScreeningEvaluator::new(
+        Arc::new(MockEvaluator),
+        Arc::new(MockEvaluator),
+        ScreeningPolicy::EnergyWindow(1.0),
+    );
+
+    // Mock energy = sum of distances from origin
+    let compact = cluster_from(&[[0.5, 0.0, 0.0], [-0.5, 0.0, 0.0]]);
+    let spread = cluster_from(&[[5.0, 0.0, 0.0], [-5.0, 0.0, 0.0]]);
+
+    assert!(screen.evaluate(&compact).is_ok());
+
+    let err = screen.evaluate(&spread).expect_err("Spread cluster should be screened out");
+    let rejection = err.downcast_ref::<ScreenedOut>().expect("Expected a ScreenedOut error");
+    assert!((rejection.cheap_energy - 10.0).abs() < 1e-9);
+
+    let stats = screen.stats();
+    assert_eq!((stats.screened, stats.forwarded, stats.rejected), (2, 1, 1));
+    assert_eq!(screen.rejected_energies(), vec![10.0]);
+    assert_eq!(classify_failure(&err), EvalFailure::Screened);
+}
+
+#[test]
+fn test_failure_classification() {
+    let detailed = failure(EvalFailure::AtomCountMismatch, "expected 12, got 10");
+    assert_eq!(classify_failure(&detailed), EvalFailure::AtomCountMismatch);
+    assert!(format!("{:#}", detailed).contains("expected 12"));
+
+    let wrapped = anyhow::Error::from(EvalFailure::Convergence).context("Geometry parsing failed");
+    assert_eq!(classify_failure(&wrapped), EvalFailure::Convergence);
+
+    assert_eq!(classify_failure(&anyhow::anyhow!("mystery")), EvalFailure::Other);
+}
+
+#[test]
+fn test_instrumented_evaluator_stats() {
+    let native: Arc<dyn Evaluator> = Arc::new(NativeEvaluator::new(Arc::new(LennardJones::reduced())));
+    let evaluator = InstrumentedEvaluator::new(native);
+    assert_eq!(evaluator.stats().unwrap().calls, 0);
+
+    evaluator.evaluate(&cluster_from(&[[0.0, 0.0, 0.0], [1.5, 0.0, 0.0]])).unwrap();
+    // Coincident atoms collapse
+    assert!(evaluator.evaluate(&cluster_from(&[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])).is_err());
+
+    let stats = evaluator.stats().unwrap();
+    assert_eq!((stats.calls, stats.successes, stats.failures), (2, 1, 1));
+    assert_eq!(stats.wall_time.total(), 2);
+    assert_eq!(stats.iterations.total(), 1);
+    assert!(stats.max_time >= stats.min_time);
+    assert!(MockEvaluator.stats().is_none());
+}
@@ -1,5 +1,5 @@
-use klmc_ultimate::core::domain::{Cluster, Atom};
-use klmc_ultimate::engine::operators::{Mutator, crossover_cut_splice};
+use klmc_ultimate::core::domain::{BhMove, Cluster, Atom};
+use klmc_ultimate::engine::operators::{Mutator, crossover_cut_splice, surface_atoms};
 use nalgebra::{Point3, Vector3};
 use rand::thread_rng;
 
@@ -31,6 +31,34 @@ fn test_mutation_rattle() {
     assert!((dist_orig - dist_new).abs() > 1e-9);
 }
 
+#[test]
+fn test_bh_surface_and_exchange_moves() {
+    // Chain: the two ends have one neighbour, the interior three have two
+    let c = create_dummy_cluster(5);
+    assert_eq!(surface_atoms(&c), vec![0, 4]);
+
+    let mut rng = thread_rng();
+    let moved = Mutator::new().surface_rattle(0.3).apply(&c, &mut rng);
+    let gap = |c: &Cluster, i: usize, j: usize| (c.atoms[i].position - c.atoms[j].position).norm();
+    assert!((gap(&moved, 1, 3) - 2.0).abs() < 1e-9, "interior atoms must not move relative to each other");
+    assert!((gap(&moved, 0, 4) - 4.0).abs() > 1e-9);
+
+    // Exchange keeps the site set but puts a different species on two of the sites
+    let swapped = Mutator::new().exchange(1).apply(&c, &mut rng);
+    let species_at = |c: &Cluster| {
+        let mut sites: Vec<(i64, usize)> = c.atoms.iter().map(|a| ((a.position.x * 1e6).round() as i64, a.element_id)).collect();
+        sites.sort();
+        sites
+    };
+    let (before, after) = (species_at(&Mutator::new().apply(&c, &mut rng)), species_at(&swapped));
+    assert_eq!(before.iter().map(|s| s.0).collect::<Vec<_>>(), after.iter().map(|s| s.0).collect::<Vec<_>>());
+    assert_eq!(before.iter().zip(&after).filter(|(a, b)| a.1 != b.1).count(), 2);
+
+    assert_eq!(BhMove::parse("surface:0.3"), Some((BhMove::Surface, 0.3)));
+    assert_eq!(BhMove::parse("Swap"), Some((BhMove::Swap, 1.0)));
+    assert_eq!(BhMove::parse("teleport"), None);
+}
+
 #[test]
 fn test_crossover() {
     let p1 = create_dummy_cluster(4); // 2 of type 0, 2 of type 1
@@ -0,0 +1,67 @@
+use klmc_ultimate::core::domain::{Cluster, Species};
+use klmc_ultimate::core::chemistry::InteractionGrid;
+use klmc_ultimate::engine::evaluator::Evaluator;
+use klmc_ultimate::engine::remote::{self, RemoteEvaluator, RemoteSettings};
+use rayon::prelude::*;
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use crate::common::MockEvaluator;
+
+mod common;
+
+/// Starts a worker on an ephemeral localhost port and returns its address.
+fn spawn_worker(max_jobs: usize) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || remote::serve(listener, Arc::new(MockEvaluator), max_jobs));
+    addr
+}
+
+/// An address nothing is listening on.
+fn dead_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+fn random_cluster() -> Cluster {
+    let species = vec![Species { radius_covalent: 0.5, ..Default::default() }];
+    let grid = InteractionGrid::new(&species, 0.5);
+    Cluster::new_random(&[6], 4.0, &grid, &mut rand::thread_rng()).unwrap()
+}
+
+#[test]
+fn test_remote_matches_local() {
+    let client = RemoteEvaluator::new(vec![(spawn_worker(2), 2)]);
+    let cluster = random_cluster();
+
+    let local = MockEvaluator.evaluate(&cluster).unwrap();
+    let remote = client.evaluate(&cluster).unwrap();
+
+    assert!((local.energy - remote.energy).abs() < 1e-12);
+    assert_eq!(remote.relaxed_cluster.unwrap().atoms.len(), cluster.atoms.len());
+
+    let probes = client.probe();
+    assert_eq!(probes[0].1.as_ref().unwrap().0, "Mock Evaluator");
+}
+
+#[test]
+fn test_remote_failover_and_parallel() {
+    let settings = RemoteSettings {
+        backoff: Duration::from_millis(50),
+        ..Default::default()
+    };
+    let client = RemoteEvaluator::new(vec![
+        (dead_address(), 2),
+        (spawn_worker(2), 2),
+        (spawn_worker(2), 2),
+    ]).with_settings(settings);
+
+    let clusters: Vec<Cluster> = (0..24).map(|_| random_cluster()).collect();
+    let ok = clusters.par_iter()
+        .filter(|c| client.evaluate(c).is_ok())
+        .count();
+
+    assert_eq!(ok, clusters.len(), "Every request should fail over to a live worker");
+}
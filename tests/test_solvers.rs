@@ -1,11 +1,29 @@
-use klmc_ultimate::core::domain::{Params, AlgorithmType, Cluster, Species};
+use klmc_ultimate::core::domain::{Acceptance, Params, AlgorithmType, Atom, Cluster, CoolingSchedule, GaMode, Lattice, MigrationTopology, Objective, Species};
+use klmc_ultimate::analysis::pareto;
 use klmc_ultimate::core::chemistry::InteractionGrid;
+use klmc_ultimate::engine::operators::MutationOp;
+use klmc_ultimate::engine::properties::{PropertyEvaluator, DIPOLE};
+use klmc_ultimate::solvers::acceptance::{self, AcceptanceCriterion, Downhill, Metropolis, Novelty, Threshold, Tsallis};
+use klmc_ultimate::solvers::aos::{OperatorPool, REWARD_BEST};
 use klmc_ultimate::solvers::ga::GeneticAlgorithm;
-use klmc_ultimate::solvers::bh::BasinHopping;
-use klmc_ultimate::solvers::SolverEvent;
-use crossbeam_channel::unbounded;
+use klmc_ultimate::solvers::bh::{BasinHopping, StepController};
+use klmc_ultimate::solvers::hof::HallOfFame;
+use klmc_ultimate::solvers::pso::ParticleSwarm;
+use klmc_ultimate::solvers::pt::{ParallelTempering, temperature_ladder};
+use klmc_ultimate::solvers::random::RandomSearch;
+use klmc_ultimate::solvers::sa::SimulatedAnnealing;
+use klmc_ultimate::solvers::scan::{ScanBox, box_sizes};
+use klmc_ultimate::solvers::ss::{SolidSolution, Sublattice};
+use klmc_ultimate::solvers::registry::{SolverContext, SolverRegistry};
+use klmc_ultimate::solvers::stopping::{StopReason, StoppingCriteria};
+use klmc_ultimate::solvers::{self, Control, GenStats, Progress, Solver, SolverCommand, SolverEvent, SolverState, Tuning};
+use klmc_ultimate::engine::evaluator::{Evaluator, EvaluationResult};
+use crossbeam_channel::{unbounded, Sender};
+use nalgebra::{Point3, Vector3};
 use std::sync::Arc;
-use crate::common::MockEvaluator;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use rand::SeedableRng;
+use crate::common::{ab_grid, MockEvaluator};
 
 mod common;
 
@@ -20,11 +38,7 @@ fn test_ga_flow() {
         ..Default::default()
     };
 
-    let species = vec![
-        Species { symbol: "A".into(), radius_covalent: 0.5, ..Default::default() },
-        Species { symbol: "B".into(), radius_covalent: 0.5, ..Default::default() },
-    ];
-    let grid = Arc::new(InteractionGrid::new(&species, 0.5));
+    let grid = ab_grid();
     let evaluator = Arc::new(MockEvaluator);
 
     let ga = GeneticAlgorithm::new(evaluator, grid, params);
@@ -37,7 +51,7 @@ fn test_ga_flow() {
 
     for msg in rx {
         match msg {
-            SolverEvent::Finished => finished = true,
+            SolverEvent::Finished(_) => finished = true,
             SolverEvent::GenerationUpdate(_) => received_stats = true,
             _ => {}
         }
@@ -48,6 +62,7 @@ fn test_ga_flow() {
 }
 
 #[test]
+#[allow(clippy::single_match)]
 fn test_bh_flow() {
     let params = Params {
         algorithm: AlgorithmType::BasinHopping,
@@ -57,11 +72,7 @@ fn test_bh_flow() {
         ..Default::default()
     };
 
-    let species = vec![
-        Species { symbol: "A".into(), radius_covalent: 0.5, ..Default::default() },
-        Species { symbol: "B".into(), radius_covalent: 0.5, ..Default::default() },
-    ];
-    let grid = Arc::new(InteractionGrid::new(&species, 0.5));
+    let grid = ab_grid();
     let evaluator = Arc::new(MockEvaluator);
 
     let bh = BasinHopping::new(evaluator, grid.clone(), params.clone());
@@ -76,10 +87,866 @@ fn test_bh_flow() {
     let mut finished = false;
     for msg in rx {
         match msg {
-            SolverEvent::Finished => finished = true,
+            SolverEvent::Finished(_) => finished = true,
             _ => {}
         }
     }
 
     assert!(finished, "BH did not finish");
 }
+
+#[test]
+fn test_bh_step_controller_targets_acceptance() {
+    let params = Params {
+        step_size: 0.2,
+        temperature: 300.0,
+        adaptive_step: true,
+        target_acceptance: 0.5,
+        adapt_interval: 10,
+        adapt_temperature: true,
+        ..Default::default()
+    };
+
+    // Everything accepted: take bigger steps at a lower temperature
+    let mut control = StepController::new(&params);
+    (0..10).for_each(|_| control.record(true));
+    assert!((control.acceptance() - 1.0).abs() < 1e-12);
+    assert!(control.step_size > 0.2 && control.temperature < 300.0);
+
+    // Nothing accepted: smaller steps at a higher temperature
+    let mut control = StepController::new(&params);
+    (0..9).for_each(|_| control.record(false));
+    assert_eq!(control.step_size, 0.2, "no change before the window closes");
+    control.record(false);
+    assert!(control.step_size < 0.2 && control.temperature > 300.0);
+
+    // Disabled: acceptance is still tracked, the move stays fixed
+    let mut control = StepController::new(&Params { adaptive_step: false, ..params.clone() });
+    (0..10).for_each(|_| control.record(true));
+    assert!((control.acceptance() - 1.0).abs() < 1e-12);
+    assert_eq!((control.step_size, control.temperature), (0.2, 300.0));
+
+    // A full run reports the adapted values
+    let grid = ab_grid();
+    let params = Params { atom_count: 4, atom_counts: vec![2, 2], max_steps: 40, seed: 3, ..params };
+    let mut rng = rand::thread_rng();
+    let start = Cluster::new_random(&params.atom_counts, params.box_size, &grid, &mut rng).unwrap();
+    let (tx, rx) = unbounded();
+    BasinHopping::new(Arc::new(MockEvaluator), grid, params).solve(start, tx);
+
+    let updates: Vec<GenStats> = rx.iter().filter_map(|m| match m {
+        SolverEvent::GenerationUpdate(s) => Some(s),
+        _ => None,
+    }).collect();
+    assert!(updates.iter().all(|u| u.diversity == 1.0 && u.acceptance.is_some_and(|a| (0.0..=1.0).contains(&a))));
+    let steps: Vec<f64> = updates.iter().filter_map(|u| u.step_size).collect();
+    assert_eq!(steps.len(), 40);
+    assert_eq!(steps[0], 0.2);
+    assert!(steps[10..].iter().any(|&s| (s - 0.2).abs() > 1e-9), "step size should adapt after the first window");
+}
+
+#[test]
+fn test_bh_acceptance_criteria() {
+    let basin = |key: &str, energy: f64| {
+        let mut c = Cluster::new("Test");
+        c.energy = Some(energy);
+        c.hash_key = Some(key.to_string());
+        c
+    };
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+    let (a, b, c) = (basin("A", 0.0), basin("B", 0.02), basin("C", 0.03));
+
+    // kT at 300 K is ~0.0259 eV
+    assert!(Threshold.accept(&a, &b, 300.0, &mut rng));
+    assert!(!Threshold.accept(&a, &c, 300.0, &mut rng));
+    assert!(!Downhill.accept(&a, &b, 300.0, &mut rng));
+    assert!(Downhill.accept(&b, &a, 300.0, &mut rng));
+    assert!(!Metropolis.accept(&a, &b, 0.0, &mut rng), "zero temperature is a quench");
+
+    // q > 1 has a heavier uphill tail than Metropolis: [1 + 0.5 ΔE / kT]^-2 ≈ 0.116 vs ≈ 0.021
+    let uphill = basin("D", 0.1);
+    let rate = |criterion: &mut dyn AcceptanceCriterion, rng: &mut rand_chacha::ChaCha8Rng| {
+        (0..10_000).filter(|_| criterion.accept(&a, &uphill, 300.0, rng)).count() as f64 / 10_000.0
+    };
+    let tsallis = rate(&mut Tsallis { q: 1.5 }, &mut rng);
+    let boltzmann = rate(&mut Metropolis, &mut rng);
+    assert!((tsallis - 0.116).abs() < 0.02, "Tsallis rate {}", tsallis);
+    assert!((boltzmann - 0.021).abs() < 0.01, "Metropolis rate {}", boltzmann);
+
+    // Novelty refuses to go back, even downhill, until the basin leaves its memory
+    let mut novelty = Novelty::new(2);
+    assert!(novelty.accept(&c, &b, 300.0, &mut rng));
+    assert!(!novelty.accept(&b, &basin("C", -1.0), 300.0, &mut rng));
+    assert!(novelty.accept(&b, &basin("E", -1.0), 300.0, &mut rng));
+    assert!(novelty.accept(&basin("E", -1.0), &basin("C", -2.0), 300.0, &mut rng), "C has been forgotten");
+
+    let params = Params { acceptance: Acceptance::Tsallis, ..Default::default() };
+    assert_eq!(acceptance::build(&params).name(), "Tsallis");
+}
+
+fn ga_params(seed: u64, ga_mode: GaMode) -> Params {
+    Params {
+        algorithm: AlgorithmType::GeneticAlgorithm,
+        seed,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        population_size: 10,
+        max_steps: 5,
+        ga_mode,
+        ..Default::default()
+    }
+}
+
+fn run_ga(params: Params) -> (Vec<f64>, usize) {
+    let grid = ab_grid();
+    let ga = GeneticAlgorithm::new(Arc::new(MockEvaluator), grid, params);
+
+    let (tx, rx) = unbounded();
+    ga.solve(tx);
+
+    let mut bests = Vec::new();
+    let mut generations = 0;
+    for msg in rx {
+        match msg {
+            SolverEvent::NewBest(c) => bests.push(c.energy.unwrap()),
+            SolverEvent::GenerationUpdate(_) => generations += 1,
+            _ => {}
+        }
+    }
+    (bests, generations)
+}
+
+#[test]
+fn test_ga_seeded_reproducible() {
+    let (a, _) = run_ga(ga_params(42, GaMode::Generational));
+    let (b, _) = run_ga(ga_params(42, GaMode::Generational));
+    assert_eq!(a, b, "Same seed should give the same trajectory");
+}
+
+#[test]
+fn test_ga_steady_state_comparable() {
+    let (gen_bests, gen_count) = run_ga(ga_params(7, GaMode::Generational));
+    let (ss_bests, ss_count) = run_ga(ga_params(7, GaMode::SteadyState));
+
+    // Same seeded starting population, same number of reported generations
+    assert_eq!(gen_bests[0], ss_bests[0]);
+    assert_eq!(gen_count, ss_count);
+    assert!(ss_bests.last().unwrap() <= &ss_bests[0]);
+}
+
+#[test]
+fn test_ga_memetic_phase() {
+    let grid = ab_grid();
+    let run = |params: Params| {
+        let (tx, rx) = unbounded();
+        GeneticAlgorithm::new(Arc::new(MockEvaluator), grid.clone(), params).solve(tx);
+        rx.iter().collect::<Vec<SolverEvent>>()
+    };
+    let memetic = |events: &[SolverEvent]| -> Vec<usize> {
+        events.iter()
+            .filter_map(|e| match e { SolverEvent::Log(m) if m.starts_with("Memetic") => Some(m.clone()), _ => None })
+            .map(|m| m.split(": ").nth(1).and_then(|r| r.split(' ').next()).unwrap().parse().unwrap())
+            .collect()
+    };
+
+    // Every second generation; the mock energy rewards any move towards the origin
+    let params = Params { memetic_interval: 2, memetic_elites: 3, memetic_steps: 10, ..ga_params(9, GaMode::Generational) };
+    let events = run(params.clone());
+    let improved = memetic(&events);
+    assert_eq!(improved.len(), 2);
+    assert!(improved.iter().all(|&n| n <= 3) && improved.iter().sum::<usize>() > 0);
+    assert!(events.iter().any(|e| matches!(e, SolverEvent::NewBest(c) if c.origin == "Memetic")));
+    let bests = |events: &[SolverEvent]| events.iter().filter_map(|e| match e { SolverEvent::NewBest(c) => c.energy, _ => None }).collect::<Vec<f64>>();
+    assert_eq!(bests(&events), bests(&run(params)), "memetic chains should be reproducible from the seed");
+
+    // Off by default, and skipped for multi-objective runs
+    assert!(memetic(&run(ga_params(9, GaMode::Generational))).is_empty());
+    let params = Params { memetic_interval: 1, objectives: vec![Objective::parse("energy")], ..ga_params(9, GaMode::Generational) };
+    assert!(memetic(&run(params)).is_empty());
+}
+
+#[test]
+fn test_ga_island_model() {
+    let params = Params {
+        islands: 3,
+        migration_interval: 2,
+        migration_topology: MigrationTopology::FullyConnected,
+        mutation_spread: 3.0,
+        ..ga_params(11, GaMode::Island)
+    };
+    let (a, gens) = run_ga(params.clone());
+    let (b, _) = run_ga(params);
+
+    assert_eq!(a, b, "Island streams should be reproducible from the master seed");
+    assert_eq!(gens, 5);
+    assert!(a.windows(2).all(|w| w[1] < w[0]), "NewBest should only report improvements");
+}
+
+#[test]
+fn test_operator_pool_pursues_rewarded_operator() {
+    let mut pool = OperatorPool::new(&MutationOp::ALL);
+    let mut rng = rand::thread_rng();
+    assert!(MutationOp::ALL.contains(&pool.select(&mut rng)));
+
+    for _ in 0..30 {
+        for op in MutationOp::ALL {
+            let reward = if op == MutationOp::Twist { REWARD_BEST } else { 0.0 };
+            pool.credit(op.name(), reward);
+        }
+        pool.credit("Refill", REWARD_BEST); // not an operator: ignored
+        pool.update();
+    }
+
+    let stats = pool.stats();
+    let total: f64 = stats.iter().map(|s| s.probability).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    let twist = stats.iter().find(|s| s.name == "Twist").unwrap();
+    assert!(twist.probability > 0.75, "Twist should dominate, got {}", twist.probability);
+    assert_eq!((twist.uses, twist.successes), (30, 30));
+    assert!(stats.iter().all(|s| s.probability >= 0.05 - 1e-9));
+}
+
+#[test]
+fn test_ga_reports_operator_probabilities() {
+    for (mode, adaptive) in [(GaMode::Generational, true), (GaMode::SteadyState, true), (GaMode::Generational, false)] {
+        let grid = ab_grid();
+        let params = Params { mutation_rate: 1.0, adaptive_operators: adaptive, ..ga_params(5, mode) };
+        let (tx, rx) = unbounded();
+        GeneticAlgorithm::new(Arc::new(MockEvaluator), grid, params).solve(tx);
+
+        let updates: Vec<_> = rx.iter().filter_map(|m| match m {
+            SolverEvent::OperatorUpdate(ops) => Some(ops),
+            _ => None,
+        }).collect();
+        if !adaptive {
+            assert!(updates.is_empty());
+            continue;
+        }
+        assert_eq!(updates.len(), 5, "{:?}: one update per generation", mode);
+        let last = updates.last().unwrap();
+        assert_eq!(last.len(), MutationOp::ALL.len());
+        assert!(last.iter().map(|o| o.uses).sum::<usize>() > 0, "{:?}: children should be credited", mode);
+    }
+}
+
+#[test]
+fn test_ga_multi_objective_pareto_front() {
+    for mode in [GaMode::Generational, GaMode::SteadyState] {
+        let species = vec![
+            Species { symbol: "A".into(), radius_covalent: 0.5, charge: 1.0, ..Default::default() },
+            Species { symbol: "B".into(), radius_covalent: 0.5, charge: -1.0, ..Default::default() },
+        ];
+        let grid = Arc::new(InteractionGrid::new(&species, 0.5));
+        let objectives = vec![Objective::parse("energy"), Objective::parse("max:dipole")];
+        let params = Params { objectives: objectives.clone(), ..ga_params(9, mode) };
+        let evaluator = Arc::new(PropertyEvaluator::new(Arc::new(MockEvaluator), vec![1.0, -1.0]));
+        let (tx, rx) = unbounded();
+        GeneticAlgorithm::new(evaluator, grid, params).solve(tx);
+
+        let front = rx.iter().filter_map(|m| match m {
+            SolverEvent::ParetoUpdate(front) => Some(front),
+            _ => None,
+        }).last().expect("multi-objective runs publish a Pareto front");
+        assert!(!front.is_empty());
+
+        let costs: Vec<Vec<f64>> = front.iter()
+            .map(|c| {
+                assert!(c.properties.contains_key(DIPOLE));
+                objectives.iter().map(|o| o.cost(c).unwrap()).collect()
+            })
+            .collect();
+        for a in &costs {
+            for b in &costs {
+                assert!(!pareto::dominates(a, b), "{:?}: front members must be mutually non-dominated", mode);
+            }
+        }
+    }
+
+    // An objective the evaluator never reports fails the start-up instead of ranking everything last
+    let grid = ab_grid();
+    let params = Params { objectives: vec![Objective::parse("energy"), Objective::parse("max:dipole")], ..ga_params(9, GaMode::Generational) };
+    let (tx, rx) = unbounded();
+    GeneticAlgorithm::new(Arc::new(MockEvaluator), grid, params).solve(tx);
+    let events: Vec<SolverEvent> = rx.iter().collect();
+    assert!(events.iter().any(|e| matches!(e, SolverEvent::Log(m) if m.contains("[dipole] not reported"))));
+    assert!(matches!(events.last(), Some(SolverEvent::Finished(StopReason::Failed))));
+}
+
+#[test]
+fn test_sa_cooling_schedules() {
+    let grid = ab_grid();
+
+    for cooling in [CoolingSchedule::Linear, CoolingSchedule::Geometric, CoolingSchedule::Adaptive] {
+        let params = Params {
+            algorithm: AlgorithmType::SimulatedAnnealing,
+            atom_count: 4,
+            atom_counts: vec![2, 2],
+            max_steps: 200,
+            temperature: 1000.0,
+            final_temperature: 1.0,
+            cooling,
+            reheat_after: 60,
+            seed: 5,
+            ..Default::default()
+        };
+        let mut rng = rand::thread_rng();
+        let start = Cluster::new_random(&params.atom_counts, params.box_size, &grid, &mut rng).unwrap();
+
+        let (tx, rx) = unbounded();
+        SimulatedAnnealing::new(Arc::new(MockEvaluator), grid.clone(), params).solve(start, tx);
+
+        let mut relative_t = Vec::new();
+        let mut finished = false;
+        for msg in rx {
+            match msg {
+                SolverEvent::GenerationUpdate(stats) => relative_t.push(stats.mutation_rate),
+                SolverEvent::Finished(_) => finished = true,
+                _ => {}
+            }
+        }
+
+        assert!(finished, "{:?}: SA did not finish", cooling);
+        assert_eq!(relative_t.len(), 200);
+        assert!(relative_t.iter().all(|t| (0.0..=1.0).contains(t)));
+        assert!(relative_t.last().unwrap() < relative_t.first().unwrap(), "{:?}: temperature did not fall", cooling);
+    }
+}
+
+#[test]
+fn test_pso_flow() {
+    let params = Params {
+        algorithm: AlgorithmType::ParticleSwarm,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        population_size: 8,
+        max_steps: 10,
+        seed: 3,
+        ..Default::default()
+    };
+
+    let grid = ab_grid();
+
+    let (tx, rx) = unbounded();
+    ParticleSwarm::new(Arc::new(MockEvaluator), grid, params).solve(tx);
+
+    let mut best = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::GenerationUpdate(stats) => best.push(stats.best_energy),
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "PSO did not finish");
+    assert_eq!(best.len(), 11);
+    // The global best never gets worse
+    assert!(best.windows(2).all(|w| w[1] <= w[0]));
+}
+
+#[test]
+fn test_parallel_tempering_flow() {
+    let params = Params {
+        algorithm: AlgorithmType::ParallelTempering,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        max_steps: 25,
+        replicas: 4,
+        temperature: 100.0,
+        max_temperature: 800.0,
+        swap_interval: 5,
+        seed: 9,
+        ..Default::default()
+    };
+
+    let ladder = temperature_ladder(&params);
+    assert_eq!(ladder.len(), 4);
+    assert!((ladder[0] - 100.0).abs() < 1e-9 && (ladder[3] - 800.0).abs() < 1e-6);
+    assert!((ladder[1] / ladder[0] - ladder[2] / ladder[1]).abs() < 1e-9);
+
+    let grid = ab_grid();
+    let mut rng = rand::thread_rng();
+    let start = Cluster::new_random(&params.atom_counts, params.box_size, &grid, &mut rng).unwrap();
+
+    let (tx, rx) = unbounded();
+    ParallelTempering::new(Arc::new(MockEvaluator), grid, params).solve(start, tx);
+
+    let mut updates = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::ReplicaUpdate(replicas) => updates.push(replicas),
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "PT did not finish");
+    assert_eq!(updates.len(), 5); // 25 steps / swap_interval 5
+    let last = updates.last().unwrap();
+    assert_eq!(last.len(), 4);
+    assert!(last.windows(2).all(|w| w[0].temperature < w[1].temperature));
+    assert!(last.iter().all(|r| (0.0..=1.0).contains(&r.acceptance_rate) && (0.0..=1.0).contains(&r.swap_rate)));
+    assert_eq!(last[3].swap_rate, 0.0);
+}
+
+#[test]
+fn test_bh_multi_walker_flow() {
+    let params = Params {
+        algorithm: AlgorithmType::BasinHopping,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        max_steps: 30,
+        walkers: 4,
+        restart_after: 5,
+        seed: 11,
+        ..Default::default()
+    };
+
+    let grid = ab_grid();
+    let mut rng = rand::thread_rng();
+    let start = Cluster::new_random(&params.atom_counts, params.box_size, &grid, &mut rng).unwrap();
+
+    let (tx, rx) = unbounded();
+    BasinHopping::new(Arc::new(MockEvaluator), grid, params).solve(start, tx);
+
+    let mut updates = Vec::new();
+    let mut bests = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::GenerationUpdate(stats) => updates.push(stats),
+            SolverEvent::NewBest(c) => bests.push(c),
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "Multi-walker BH did not finish");
+    assert_eq!(updates.len(), 3); // 30 steps in blocks of 10
+    assert!(updates.iter().all(|u| u.pop_size == 4));
+    assert!(!bests.is_empty());
+    assert!(bests.iter().all(|c| c.hash_key.is_some()));
+
+    // Every published minimum is distinct
+    let mut keys: Vec<_> = bests.iter().map(|c| c.hash_key.clone()).collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), bests.len());
+}
+
+/// Mock evaluator that asks the solver to stop on its `after`-th call.
+struct StopAfter {
+    after: usize,
+    calls: AtomicUsize,
+    stop: Sender<SolverCommand>,
+}
+
+impl Evaluator for StopAfter {
+    fn evaluate(&self, cluster: &Cluster) -> anyhow::Result<EvaluationResult> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) + 1 == self.after {
+            let _ = self.stop.send(SolverCommand::Stop);
+        }
+        MockEvaluator.evaluate(cluster)
+    }
+
+    fn name(&self) -> &str { "Stop After" }
+}
+
+#[test]
+fn test_bh_multi_walker_stop_mid_block() {
+    let params = Params {
+        algorithm: AlgorithmType::BasinHopping,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        max_steps: 30,
+        walkers: 2,
+        temperature: 1e6, // Accept nearly every move
+        seed: 11,
+        ..Default::default()
+    };
+
+    let grid = ab_grid();
+    let mut rng = rand::thread_rng();
+    let start = Cluster::new_random(&params.atom_counts, params.box_size, &grid, &mut rng).unwrap();
+
+    // Stop a few evaluations into the first block of 10 steps
+    let (cmd_tx, cmd_rx) = unbounded();
+    let evaluator = Arc::new(StopAfter { after: 6, calls: AtomicUsize::new(0), stop: cmd_tx });
+    let mut bh = BasinHopping::new(evaluator, grid, params).with_start(start);
+    let (tx, rx) = unbounded();
+    let reason = solvers::run_controlled(&mut bh, tx, &Control::new(cmd_rx), &mut StoppingCriteria::default());
+
+    assert_eq!(reason, StopReason::Stopped);
+    assert!(bh.state().step < 10, "partial block counted as complete: {}", bh.state().step);
+    let events: Vec<SolverEvent> = rx.iter().collect();
+    assert!(events.iter().all(|e| !matches!(e, SolverEvent::GenerationUpdate(u) if u.generation >= 10)));
+
+    // Walkers that ran ahead of the slowest one still count their own moves
+    let summary = events.iter().find_map(|e| match e {
+        SolverEvent::Log(m) if m.starts_with("BH Finished") => Some(m.clone()),
+        _ => None,
+    }).expect("BH reports a summary");
+    let ratio = summary.split("acceptance ").nth(1).and_then(|r| r.split(',').next()).unwrap();
+    let (accepted, steps) = ratio.split_once('/').unwrap();
+    let (accepted, steps): (usize, usize) = (accepted.parse().unwrap(), steps.parse().unwrap());
+    assert!(accepted <= steps, "acceptance {}/{} above 100%", accepted, steps);
+}
+
+#[test]
+fn test_hall_of_fame_dedup() {
+    let isomer = |key: &str, energy: f64| {
+        let mut c = Cluster::new("test");
+        c.energy = Some(energy);
+        c.hash_key = Some(key.to_string());
+        c
+    };
+    let mut a = isomer("a", -1.0);
+    let b = isomer("b", -2.0);
+
+    let mut hof = HallOfFame::new(2);
+    assert!(hof.insert(&a, 0));
+    assert!(hof.insert(&b, 1));
+    a.energy = Some(-1.5);
+    assert!(!hof.insert(&a, 2)); // Same isomer, better geometry: kept, not new
+    assert_eq!(hof.finder_of(Some("a")), Some(0));
+    assert_eq!(hof.entries()[1].cluster.energy, Some(-1.5));
+
+    assert!(!hof.insert(&isomer("c", 0.0), 3)); // Full and worse than everything on record
+    assert_eq!(hof.best().and_then(|c| c.hash_key.clone()), Some("b".into()));
+}
+
+#[test]
+fn test_scan_box_flow() {
+    let params = Params {
+        algorithm: AlgorithmType::ScanBox,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        population_size: 6,
+        scan_box_min: 4.0,
+        scan_box_max: 8.0,
+        scan_steps: 3,
+        seed: 5,
+        ..Default::default()
+    };
+    assert_eq!(box_sizes(&params), vec![4.0, 6.0, 8.0]);
+
+    let grid = ab_grid();
+
+    let (tx, rx) = unbounded();
+    ScanBox::new(Arc::new(MockEvaluator), grid, params).solve(tx);
+
+    let mut scan = Vec::new();
+    let mut bests = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::BoxScanUpdate(s) => scan = s,
+            SolverEvent::NewBest(c) => bests.push(c),
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "ScanBox did not finish");
+    assert_eq!(scan.len(), 3);
+    assert!(scan.iter().all(|s| s.samples == 6 && s.valid <= 6));
+    assert!(scan.iter().all(|s| s.best_energy <= s.mean_energy && s.mean_energy <= s.worst_energy));
+    assert!(!bests.is_empty());
+
+    // Mock energy grows with spread, so the smallest box gives the lowest minimum
+    let lowest = bests.iter().filter_map(|c| c.energy).fold(f64::MAX, f64::min);
+    assert_eq!(scan[0].best_energy, Some(lowest));
+}
+
+#[test]
+fn test_solid_solution_flow() {
+    let sublattice = Sublattice { sites: (0..8).collect(), fractions: vec![(0, 0.75), (1, 0.25)] };
+    assert_eq!(sublattice.counts(), vec![(0, 6), (1, 2)]);
+    let uneven = Sublattice { sites: (0..10).collect(), fractions: vec![(0, 0.33), (1, 0.33), (2, 0.34)] };
+    assert_eq!(uneven.counts().iter().map(|(_, n)| n).sum::<usize>(), 10);
+
+    // 2x2x2 simple cubic host of species 0
+    let mut host = Cluster::new("Host");
+    for i in 0..8 {
+        host.atoms.push(Atom {
+            element_id: 0,
+            position: Point3::new((i & 1) as f64 * 2.0, ((i >> 1) & 1) as f64 * 2.0, ((i >> 2) & 1) as f64 * 2.0),
+            velocity: Vector3::zeros(),
+            force: Vector3::zeros(),
+            is_fixed: false,
+        });
+    }
+
+    let params = Params {
+        algorithm: AlgorithmType::SolidSolution,
+        max_steps: 30,
+        seed: 3,
+        ..Default::default()
+    };
+    let grid = ab_grid();
+
+    // A molecular host has no lattice to substitute on
+    assert!(SolidSolution::new(Arc::new(MockEvaluator), grid.clone(), host.clone(), sublattice.clone(), params.clone()).is_err());
+
+    host.lattice = Lattice::new(Vector3::new(4.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(0.0, 0.0, 4.0));
+    let solver = SolidSolution::new(Arc::new(MockEvaluator), grid, host, sublattice, params).unwrap();
+
+    let (tx, rx) = unbounded();
+    solver.solve(tx);
+
+    let mut updates = Vec::new();
+    let mut bests = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::GenerationUpdate(stats) => updates.push(stats),
+            SolverEvent::NewBest(c) => bests.push(c),
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "Solid solution did not finish");
+    assert_eq!(updates.len(), 30);
+    assert!(bests.iter().all(|c| c.atoms.iter().filter(|a| a.element_id == 1).count() == 2));
+    // Only 3 symmetry-distinct orderings of 2 B atoms exist, so at most 3 of 31 requests are relaxed
+    let last = updates.last().unwrap();
+    assert!(last.diversity <= 3.0 / 31.0 + 1e-9);
+}
+
+#[test]
+fn test_random_search_flow() {
+    let params = Params {
+        algorithm: AlgorithmType::RandomSearch,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        population_size: 8,
+        max_steps: 4,
+        seed: 13,
+        ..Default::default()
+    };
+
+    let grid = ab_grid();
+
+    let (tx, rx) = unbounded();
+    RandomSearch::new(Arc::new(MockEvaluator), grid, params).solve(tx);
+
+    let mut updates = Vec::new();
+    let mut spectrum = Vec::new();
+    let mut finished = false;
+    for msg in rx {
+        match msg {
+            SolverEvent::GenerationUpdate(stats) => updates.push(stats),
+            SolverEvent::SpectrumUpdate(s) => spectrum = s,
+            SolverEvent::Finished(_) => finished = true,
+            _ => {}
+        }
+    }
+
+    assert!(finished, "Random search did not finish");
+    assert_eq!(updates.len(), 5); // Initial round + 4, like a GA run
+    assert!(updates.iter().all(|u| u.pop_size == 8 && (0.0..=1.0).contains(&u.diversity)));
+    assert!(updates.windows(2).all(|w| w[1].best_energy <= w[0].best_energy));
+
+    assert!(!spectrum.is_empty());
+    assert!(spectrum.windows(2).all(|w| w[0].energy <= w[1].energy));
+    assert_eq!(spectrum[0].energy, updates.last().unwrap().best_energy);
+    assert!(spectrum.iter().map(|m| m.hits).sum::<usize>() <= 40);
+}
+
+#[test]
+fn test_solver_registry_lookup() {
+    let registry = SolverRegistry::builtin();
+    assert_eq!(registry.find("BH").map(|e| e.algorithm), Some(AlgorithmType::BasinHopping));
+    assert_eq!(registry.find("solid-solution").map(|e| e.algorithm), Some(AlgorithmType::SolidSolution));
+    assert!(registry.find("nope").is_none());
+    let err = registry.lookup("minhop").err().expect("unknown names are an error").to_string();
+    assert!(err.contains("'minhop'") && err.contains("mh/minima-hopping") && err.contains("random"), "{}", err);
+    assert_eq!(registry.entries().len(), 9);
+
+    let grid = ab_grid();
+    let ctx = |algorithm| SolverContext::new(Arc::new(MockEvaluator), grid.clone(), Params { algorithm, ..Default::default() });
+
+    let ga = registry.build(ctx(AlgorithmType::GeneticAlgorithm)).unwrap();
+    assert_eq!(ga.name(), "Genetic Algorithm");
+    // Missing required inputs are reported, not panicked on
+    assert!(registry.build(ctx(AlgorithmType::MinimaHopping)).is_err());
+    assert!(registry.build(ctx(AlgorithmType::SolidSolution)).is_err());
+}
+
+#[test]
+fn test_solver_step_lifecycle() {
+    let params = Params {
+        algorithm: AlgorithmType::BasinHopping,
+        atom_count: 4,
+        atom_counts: vec![2, 2],
+        max_steps: 5,
+        seed: 3,
+        ..Default::default()
+    };
+    let grid = ab_grid();
+    let mut solver = SolverRegistry::builtin()
+        .build(SolverContext::new(Arc::new(MockEvaluator), grid, params))
+        .unwrap();
+
+    let (tx, rx) = unbounded();
+    let control = Control::detached();
+    solver.init(&tx).unwrap();
+    assert_eq!(solver.state().step, 0);
+
+    let mut steps = 1;
+    while solver.step(&tx, &control) == Progress::Continue {
+        steps += 1;
+    }
+    assert_eq!(steps, 5);
+    assert_eq!(solver.step(&tx, &control), Progress::Done);
+
+    let state = solver.state();
+    assert_eq!(state.step, 5);
+    assert!(state.best.and_then(|c| c.energy).is_some());
+
+    solver.finish(&tx);
+    drop(tx);
+    let events: Vec<SolverEvent> = rx.iter().collect();
+    assert_eq!(events.iter().filter(|e| matches!(e, SolverEvent::GenerationUpdate(_))).count(), 5);
+    assert!(!events.iter().any(|e| matches!(e, SolverEvent::Finished(_))), "Finished is the driver's job");
+}
+
+#[test]
+fn test_solver_control_pause_snapshot_stop() {
+    let grid = ab_grid();
+    let params = ga_params(5, GaMode::Generational);
+    let max_steps = params.max_steps;
+
+    // Paused before the first generation: nothing happens until Resume
+    let (cmd_tx, cmd_rx) = unbounded();
+    let (tx, rx) = unbounded();
+    cmd_tx.send(SolverCommand::Pause).unwrap();
+    let mut ga = GeneticAlgorithm::new(Arc::new(MockEvaluator), grid.clone(), params.clone());
+    let worker = std::thread::spawn(move || solvers::run_controlled(&mut ga, tx, &Control::new(cmd_rx), &mut StoppingCriteria::default()));
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    assert!(!rx.try_iter().any(|e| matches!(e, SolverEvent::GenerationUpdate(_))));
+    cmd_tx.send(SolverCommand::Resume).unwrap();
+    worker.join().unwrap();
+    let updates = rx.iter().filter(|e| matches!(e, SolverEvent::GenerationUpdate(_))).count();
+    assert_eq!(updates, max_steps);
+
+    // Stop ends the run cleanly: queued snapshot answered, final report and Finished still sent
+    let (cmd_tx, cmd_rx) = unbounded();
+    let (tx, rx) = unbounded();
+    cmd_tx.send(SolverCommand::Snapshot).unwrap();
+    cmd_tx.send(SolverCommand::Stop).unwrap();
+    let mut ga = GeneticAlgorithm::new(Arc::new(MockEvaluator), grid, params);
+    solvers::run_controlled(&mut ga, tx, &Control::new(cmd_rx), &mut StoppingCriteria::default());
+
+    let events: Vec<SolverEvent> = rx.iter().collect();
+    assert!(events.iter().any(|e| matches!(e, SolverEvent::Snapshot(s) if s.step == 0 && s.best.is_some())));
+    assert!(!events.iter().any(|e| matches!(e, SolverEvent::GenerationUpdate(_))));
+    assert!(events.iter().any(|e| matches!(e, SolverEvent::Log(m) if m.contains("stopped at step 0"))));
+    assert!(events.iter().any(|e| matches!(e, SolverEvent::Log(m) if m.starts_with("GA Finished"))));
+    assert!(matches!(events.last(), Some(SolverEvent::Finished(StopReason::Stopped))));
+}
+
+#[test]
+fn test_live_tuning() {
+    let grid = ab_grid();
+
+    // GA: changes queued before the first generation are applied and confirmed before it runs
+    let (cmd_tx, cmd_rx) = unbounded();
+    let (tx, rx) = unbounded();
+    cmd_tx.send(SolverCommand::Tune(Tuning::MutationRate(0.9))).unwrap();
+    cmd_tx.send(SolverCommand::Tune(Tuning::Temperature(500.0))).unwrap();
+    cmd_tx.send(SolverCommand::Tune(Tuning::Extinction)).unwrap();
+    let mut ga = GeneticAlgorithm::new(Arc::new(MockEvaluator), grid.clone(), ga_params(8, GaMode::Generational));
+    solvers::run_controlled(&mut ga, tx, &Control::new(cmd_rx), &mut StoppingCriteria::default());
+
+    let events: Vec<SolverEvent> = rx.iter().collect();
+    let logged = |text: &str| events.iter().any(|e| matches!(e, SolverEvent::Log(m) if m.contains(text)));
+    assert!(logged("Mutation rate set to 0.90"));
+    assert!(logged("does not support live changes to the temperature"));
+    assert!(logged("Mass Extinction (Manual)"));
+    let tuned: Vec<Tuning> = events.iter().filter_map(|e| match e { SolverEvent::Tuned(t) => Some(*t), _ => None }).collect();
+    assert_eq!(tuned, vec![Tuning::MutationRate(0.9), Tuning::Extinction]);
+    let first = events.iter().find_map(|e| match e { SolverEvent::GenerationUpdate(s) => Some(s.clone()), _ => None }).unwrap();
+    assert!((first.mutation_rate - 0.9).abs() < 1e-9);
+
+    // BH: temperature and step size reach the walker's controller
+    let params = Params { atom_count: 4, atom_counts: vec![2, 2], max_steps: 3, seed: 4, ..Default::default() };
+    let mut bh = BasinHopping::new(Arc::new(MockEvaluator), grid, params);
+    let (tx, rx) = unbounded();
+    bh.init(&tx).unwrap();
+    assert!(bh.tune(&Tuning::Temperature(1200.0)).unwrap().contains("1200.0 K"));
+    assert!(bh.tune(&Tuning::StepSize(0.4)).is_ok());
+    assert!(bh.tune(&Tuning::MutationRate(0.5)).is_err());
+    bh.step(&tx, &Control::detached());
+    drop(tx);
+    let stats = rx.iter().find_map(|e| match e { SolverEvent::GenerationUpdate(s) => Some(s), _ => None }).unwrap();
+    assert_eq!(stats.temperature, Some(1200.0));
+    assert_eq!(stats.step_size, Some(0.4));
+}
+
+#[test]
+fn test_stopping_criteria() {
+    let grid = ab_grid();
+    let params = ga_params(6, GaMode::Generational);
+    assert!(!StoppingCriteria::new(&params).is_active());
+
+    let run = |params: Params| {
+        let mut stopping = StoppingCriteria::new(&params);
+        let evaluator = stopping.monitor(Arc::new(MockEvaluator));
+        let mut ga = GeneticAlgorithm::new(evaluator, grid.clone(), params);
+        let (tx, rx) = unbounded();
+        let reason = solvers::run_controlled(&mut ga, tx, &Control::detached(), &mut stopping);
+        let events: Vec<SolverEvent> = rx.iter().collect();
+        let generations = events.iter().filter(|e| matches!(e, SolverEvent::GenerationUpdate(_))).count();
+        assert!(matches!(events.last(), Some(SolverEvent::Finished(r)) if *r == reason));
+        (reason, generations)
+    };
+
+    // Every criterion is checked after the first step
+    let (reason, generations) = run(Params { target_energy: Some(1e6), ..params.clone() });
+    assert!(matches!(reason, StopReason::TargetEnergy(e) if e <= 1e6));
+    assert_eq!(generations, 1);
+    let (reason, generations) = run(Params { max_evaluations: 1, ..params.clone() });
+    assert!(matches!(reason, StopReason::EvaluationLimit(n) if n >= 1));
+    assert_eq!(generations, 1);
+    let (reason, _) = run(Params { time_limit: 1e-9, ..params.clone() });
+    assert!(matches!(reason, StopReason::TimeLimit(_)));
+    let (reason, generations) = run(params.clone());
+    assert_eq!(reason, StopReason::Completed);
+    assert_eq!(generations, params.max_steps);
+
+    // Stall counts steps since the best last improved
+    let mut stopping = StoppingCriteria::new(&Params { stall_steps: 2, ..Default::default() });
+    let mut cluster = Cluster::new("Test");
+    cluster.energy = Some(-1.0);
+    let state = |step: usize| SolverState { step, best: Some(cluster.clone()) };
+    assert_eq!(stopping.check(&state(1)), None);
+    assert_eq!(stopping.check(&state(2)), None);
+    assert_eq!(stopping.check(&state(3)), Some(StopReason::Stalled(2)));
+
+    // Repeat hits count evaluations relaxing into the solver's best structure
+    let mut stopping = StoppingCriteria::new(&Params { repeat_hits: 3, ..Default::default() });
+    let evaluator = stopping.monitor(Arc::new(MockEvaluator));
+    let mut far = cluster.clone();
+    far.atoms.push(Atom { element_id: 0, position: Point3::new(5.0, 0.0, 0.0), velocity: Vector3::zeros(), force: Vector3::zeros(), is_fixed: false });
+    evaluator.evaluate(&cluster).unwrap();
+    assert_eq!(stopping.hits(), 0); // No best known yet
+    assert_eq!(stopping.check(&state(1)), None);
+    assert_eq!(stopping.hits(), 1);
+    for c in [&far, &cluster, &far] {
+        evaluator.evaluate(c).unwrap();
+    }
+    assert_eq!(stopping.evaluations(), 4);
+    assert_eq!(stopping.hits(), 2);
+    assert_eq!(stopping.check(&state(2)), None);
+    evaluator.evaluate(&cluster).unwrap();
+    assert_eq!(stopping.check(&state(3)), Some(StopReason::Converged(3)));
+
+    // A new best restarts the count, whatever its energy
+    let new_best = SolverState { step: 4, best: Some(far.clone()) };
+    assert_eq!(stopping.check(&new_best), None);
+    assert_eq!(stopping.hits(), 1);
+}